@@ -1,10 +1,17 @@
 // build.rs
+#[cfg(feature = "ephemeris")]
 use std::env;
+#[cfg(feature = "ephemeris")]
 use std::fs;
 
-use bindgen;
-
 fn main() {
+    // 未启用 ephemeris 特性（如 no_std 构建）时不编译 Swiss Ephemeris
+    #[cfg(feature = "ephemeris")]
+    build_swisseph();
+}
+
+#[cfg(feature = "ephemeris")]
+fn build_swisseph() {
     println!("cargo:rerun-if-changed=c_vendor/swisseph/");
     
     let target = env::var("TARGET").expect("TARGET not set");
@@ -1,5 +1,17 @@
+// 依赖 Swiss Ephemeris 的部分仅在启用 ephemeris 特性时编译
+#[cfg(feature = "ephemeris")]
+pub mod aspect;
+#[cfg(feature = "ephemeris")]
 pub mod calculate;
+#[cfg(feature = "ephemeris")]
 pub mod ephemeris;
+#[cfg(feature = "ephemeris")]
+pub mod jie_qi;
+#[cfg(feature = "ephemeris")]
+pub mod search;
 pub mod chinese;
+pub mod lunar;
+#[cfg(feature = "ephemeris")]
 pub mod swisseph_sys;
-pub mod swisseph;
+#[cfg(feature = "ephemeris")]
+pub mod swisseph;
\ No newline at end of file
@@ -0,0 +1,302 @@
+// src/calendar/aspect.rs - 相位
+// 取代 Calculate::compute_aspects 的整数矩阵：按可配置的相位表求两组天体间的相位、容许度与入相出相，
+// 并以 search 的求根方法求相位精确成立之时
+use serde::Serialize;
+use crate::calendar::calculate::Calculate;
+use crate::calendar::ephemeris::EphemerisError;
+use crate::calendar::search::{self, SearchOptions, SHADOW_LIMIT};
+use crate::concepts::traits::ChineseName;
+
+/// 相位种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum AspectKind {
+    /// 合：同度或同宫
+    Conjunction,
+    /// 六分：相距60°
+    Sextile,
+    /// 刑：相距90°，七政家称四正
+    Square,
+    /// 三合：相距120°，七政家称拱
+    Trine,
+    /// 冲：相距180°，七政家称对照
+    Opposition,
+}
+
+impl ChineseName for AspectKind {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            AspectKind::Conjunction => "合",
+            AspectKind::Sextile => "六分",
+            AspectKind::Square => "刑",
+            AspectKind::Trine => "三合",
+            AspectKind::Opposition => "冲",
+        }
+    }
+}
+
+impl AspectKind {
+    /// 相位的角度（度）
+    pub fn angle(&self) -> f64 {
+        match self {
+            AspectKind::Conjunction => 0.0,
+            AspectKind::Sextile => 60.0,
+            AspectKind::Square => 90.0,
+            AspectKind::Trine => 120.0,
+            AspectKind::Opposition => 180.0,
+        }
+    }
+}
+
+/// 相位的判断依据
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum AspectBasis {
+    /// 按黄经相距的度数与容许度
+    #[default]
+    Degree,
+    /// 按所在的30°宫相距几宫，不论度数，七政四余的同宫、对照、三合、四正即此
+    Palace,
+}
+
+/// 相位表中的一项
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AspectRule {
+    /// 相位种类
+    pub kind: AspectKind,
+    /// 容许度（度），按宫判断时不用
+    pub orb: f64,
+}
+
+/// 相位表
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AspectSet {
+    /// 判断依据
+    pub basis: AspectBasis,
+    /// 各相位及其容许度，按先后匹配
+    pub rules: Vec<AspectRule>,
+}
+
+impl AspectSet {
+    /// 西法五种主要相位：合、冲、三合容许8°，刑7°，六分6°
+    pub fn western() -> Self {
+        let rule = |kind, orb| AspectRule { kind, orb };
+        AspectSet {
+            basis: AspectBasis::Degree,
+            rules: vec![
+                rule(AspectKind::Conjunction, 8.0),
+                rule(AspectKind::Opposition, 8.0),
+                rule(AspectKind::Trine, 8.0),
+                rule(AspectKind::Square, 7.0),
+                rule(AspectKind::Sextile, 6.0),
+            ],
+        }
+    }
+
+    /// 七政四余按宫论：同宫为合，对宫为冲（对照），相隔四宫为三合（拱），相隔三宫为刑（四正）
+    pub fn qi_zheng() -> Self {
+        let rule = |kind| AspectRule { kind, orb: 0.0 };
+        AspectSet {
+            basis: AspectBasis::Palace,
+            rules: vec![
+                rule(AspectKind::Conjunction),
+                rule(AspectKind::Opposition),
+                rule(AspectKind::Trine),
+                rule(AspectKind::Square),
+            ],
+        }
+    }
+
+    /// 两个黄经之间成立的相位
+    pub fn find(&self, first: f64, second: f64) -> Option<AspectKind> {
+        let separation = Calculate::get_degree_gap(first, second);
+        let rule = match self.basis {
+            AspectBasis::Degree => self.rules.iter().find(|rule| (separation - rule.kind.angle()).abs() <= rule.orb),
+            AspectBasis::Palace => {
+                let signs = |longitude: f64| (longitude.rem_euclid(360.0) / 30.0) as i32 % 12;
+                let apart = (signs(first) - signs(second)).rem_euclid(12);
+                let angle = apart.min(12 - apart) as f64 * 30.0;
+                self.rules.iter().find(|rule| rule.kind.angle() == angle)
+            }
+        };
+        rule.map(|rule| rule.kind)
+    }
+}
+
+/// 入相或出相
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum AspectPhase {
+    /// 入相：距精确相位越来越近
+    Applying,
+    /// 出相：距精确相位越来越远
+    Separating,
+    /// 恰在精确相位上
+    Exact,
+}
+
+impl ChineseName for AspectPhase {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            AspectPhase::Applying => "入相",
+            AspectPhase::Separating => "出相",
+            AspectPhase::Exact => "正相",
+        }
+    }
+}
+
+/// 参与相位计算的一点
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AspectPoint<B> {
+    /// 天体或虚点
+    pub body: B,
+    /// 黄经（度）
+    pub longitude: f64,
+    /// 黄经速度（度/日）
+    pub speed: f64,
+}
+
+/// 一个相位
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Aspect<B> {
+    /// 第一组中的天体
+    pub first: B,
+    /// 第二组中的天体
+    pub second: B,
+    /// 相位种类
+    pub kind: AspectKind,
+    /// 两者黄经相距（度，0..180）
+    pub separation: f64,
+    /// 相距减去相位角度（度），负值为未及、正值为已过
+    pub orb: f64,
+    /// 入相或出相
+    pub phase: AspectPhase,
+    /// 相位精确成立的世界时儒略日，未求或求不到时为 None
+    pub exact: Option<f64>,
+}
+
+impl<B: Copy> Aspect<B> {
+    /// 两点之间的相位，不成相位时为 None；不求精确时刻
+    pub fn between(first: &AspectPoint<B>, second: &AspectPoint<B>, set: &AspectSet) -> Option<Self> {
+        let kind = set.find(first.longitude, second.longitude)?;
+        let difference = search::wrap(first.longitude - second.longitude);
+        let separation = difference.abs();
+        let orb = separation - kind.angle();
+        // 相距的变化率，与 orb 同号则离精确相位越来越远
+        let rate = if difference < 0.0 { second.speed - first.speed } else { first.speed - second.speed };
+        let phase = if orb == 0.0 {
+            AspectPhase::Exact
+        } else if orb * rate < 0.0 {
+            AspectPhase::Applying
+        } else {
+            AspectPhase::Separating
+        };
+        Some(Aspect { first: first.body, second: second.body, kind, separation, orb, phase, exact: None })
+    }
+}
+
+/// 两组之间的相位，如本命盘与流年盘，按第一组、第二组的顺序排列
+pub fn aspects_between<B: Copy>(first: &[AspectPoint<B>], second: &[AspectPoint<B>], set: &AspectSet) -> Vec<Aspect<B>> {
+    first
+        .iter()
+        .flat_map(|a| second.iter().filter_map(move |b| Aspect::between(a, b, set)))
+        .collect()
+}
+
+/// 同一组内两两之间的相位，每对只列一次
+pub fn aspects_within<B: Copy>(points: &[AspectPoint<B>], set: &AspectSet) -> Vec<Aspect<B>> {
+    points
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| points[i + 1..].iter().filter_map(move |b| Aspect::between(a, b, set)))
+        .collect()
+}
+
+/// 求相位在 near 前后最近一次精确成立的世界时儒略日，找不到时为 None
+/// difference(t) 返回两者黄经之差（第一减第二）及其变化率；查找范围按 orb 与变化率估计，最多前后各 SHADOW_LIMIT 日
+pub fn exact_time<F>(mut difference: F, aspect: &Aspect<impl Copy>, near: f64, options: &SearchOptions) -> Result<Option<f64>, EphemerisError>
+where
+    F: FnMut(f64) -> Result<(f64, f64), EphemerisError>,
+{
+    // 罗计之类恒成相位者不必再求
+    if aspect.orb.abs() < 1e-9 {
+        return Ok(Some(near));
+    }
+    let (_, rate) = difference(near)?;
+    let span = (aspect.orb.abs() / rate.abs().max(1e-3) * 2.0 + options.step).min(SHADOW_LIMIT);
+    let angle = aspect.kind.angle();
+    let targets: &[f64] = if angle == 0.0 || angle == 180.0 { &[angle] } else { &[angle, -angle] };
+    let mut best: Option<f64> = None;
+    for &target in targets {
+        let mut offset = |t: f64| difference(t).map(|(value, rate)| (search::wrap(value - target), rate));
+        let mut value = |t: f64| offset(t).map(|(value, _)| value);
+        let found = search::brackets(&mut value, near - span, near + span, options.step, 90.0)?;
+        for (lo, hi, f_lo) in found {
+            let julian_day = search::find_root(&mut offset, lo, hi, f_lo, options)?;
+            match best {
+                Some(best) if (best - near).abs() <= (julian_day - near).abs() => {}
+                _ => best = Some(julian_day),
+            }
+        }
+    }
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(body: &'static str, longitude: f64, speed: f64) -> AspectPoint<&'static str> {
+        AspectPoint { body, longitude, speed }
+    }
+
+    #[test]
+    fn test_aspects() {
+        let set = AspectSet::western();
+        // 火星在10°、土星在103°：相距93°为刑，火星较快而相距渐缩向90°，入相
+        let mars = point("火", 10.0, 0.7);
+        let saturn = point("土", 103.0, 0.03);
+        let aspect = Aspect::between(&mars, &saturn, &set).unwrap();
+        assert_eq!(aspect.kind, AspectKind::Square);
+        assert!((aspect.orb - 3.0).abs() < 1e-9);
+        assert_eq!(aspect.phase, AspectPhase::Applying);
+        // 月在355°追近5°的日：入相合，跨过0°仍算相距10°
+        let moon = point("月", 355.0, 13.0);
+        let sun = point("日", 5.0, 1.0);
+        let aspect = Aspect::between(&moon, &sun, &set);
+        assert!(aspect.is_none());
+        let sun = point("日", 2.0, 1.0);
+        let aspect = Aspect::between(&moon, &sun, &set).unwrap();
+        assert_eq!((aspect.kind, aspect.phase), (AspectKind::Conjunction, AspectPhase::Applying));
+        let aspect = Aspect::between(&sun, &moon, &set).unwrap();
+        assert_eq!(aspect.phase, AspectPhase::Applying);
+
+        // 同组内每对只列一次，不与自身成相
+        let points = [mars, saturn, moon, sun];
+        let within = aspects_within(&points, &set);
+        assert!(within.iter().all(|aspect| aspect.first != aspect.second));
+        assert_eq!(within.len(), 3);
+        assert_eq!(aspects_between(&points, &points, &set).len(), 2 * 3 + 4);
+
+        // 按宫论：火星在戌宫（0-30°），土星103°在未宫，相隔三宫为四正
+        let set = AspectSet::qi_zheng();
+        assert_eq!(set.find(10.0, 103.0), Some(AspectKind::Square));
+        assert_eq!(set.find(29.0, 241.0), Some(AspectKind::Trine));
+        assert_eq!(set.find(29.0, 31.0), None);
+        assert_eq!(set.find(5.0, 25.0), Some(AspectKind::Conjunction));
+    }
+
+    #[test]
+    fn test_exact_time() {
+        // 匀速运动：第一点在7°以每日1°追赶静止于97°的第二点，t=10 时恰相距90°，精确时刻即为此时
+        let first = point("甲", 7.0, 1.0);
+        let second = point("乙", 97.0, 0.0);
+        let aspect = Aspect::between(&first, &second, &AspectSet::western()).unwrap();
+        let difference = |t: f64| Ok((7.0 + (t - 10.0) - 97.0, 1.0));
+        let exact = exact_time(difference, &aspect, 10.0, &SearchOptions::default()).unwrap().unwrap();
+        assert!((exact - 10.0).abs() < 1e-3);
+        // 第一点在4°时尚差3°，3日后（t=13）成相
+        let first = point("甲", 4.0, 1.0);
+        let aspect = Aspect::between(&first, &second, &AspectSet::western()).unwrap();
+        let difference = |t: f64| Ok((4.0 + (t - 10.0) - 97.0, 1.0));
+        let exact = exact_time(difference, &aspect, 10.0, &SearchOptions::default()).unwrap().unwrap();
+        assert!((exact - 13.0).abs() < 1e-3);
+    }
+}
@@ -1,9 +1,43 @@
 use std::collections::HashMap;
 use std::os::raw::c_char;
+use crate::concepts::luopan::LuopanReading;
+use crate::concepts::mountain::Mountain;
+use crate::calendar::search::SearchOptions;
 use crate::calendar::swisseph::{SwissEph, SE_SUN, SE_MOON, SE_MERCURY, SE_VENUS, SE_MARS, 
                                SE_JUPITER, SE_SATURN, SE_URANUS, SE_NEPTUNE, SE_PLUTO, SE_MEAN_NODE, 
                                SE_TRUE_NODE, SEFLG_SWIEPH, SEFLG_SIDEREAL};
 
+// 以下各表按 SE_SUN..SE_SATURN 的编号排列：日、月、水、金、火、木、土
+
+/// 平均日行度（度/日），水、金随日而行，平行与日同
+pub const MEAN_SPEED: [f64; 7] = [0.9856, 13.1764, 0.9856, 0.9856, 0.5240, 0.0831, 0.0335];
+/// 日行度绝对值小于此值为留（度/日），日月不留
+pub const STATIONARY_GAP: [f64; 7] = [0.0, 0.0, 0.1, 0.1, 0.05, 0.008, 0.0035];
+/// 与太阳的角距小于此值为伏（度），从《授时历》伏见度；月以朔前后各约一日为伏
+pub const INVISIBLE_GAP: [f64; 7] = [0.0, 12.0, 19.0, 10.5, 19.0, 13.0, 18.0];
+/// 迟疾相对平行的比例：日取百分之一点五，月取百分之五，五星取一成
+pub const SPEED_RATIO: [f64; 7] = [0.015, 0.05, 0.1, 0.1, 0.1, 0.1, 0.1];
+/// 顺行而日行度低于此值为迟（度/日），即平行减去 SPEED_RATIO 所定的比例
+pub const SLOW_SPEED: [f64; 7] = {
+    let mut speed = MEAN_SPEED;
+    let mut i = 0;
+    while i < speed.len() {
+        speed[i] = MEAN_SPEED[i] * (1.0 - SPEED_RATIO[i]);
+        i += 1;
+    }
+    speed
+};
+/// 顺行而日行度高于此值为疾（度/日），即平行加上 SPEED_RATIO 所定的比例
+pub const FAST_SPEED: [f64; 7] = {
+    let mut speed = MEAN_SPEED;
+    let mut i = 0;
+    while i < speed.len() {
+        speed[i] = MEAN_SPEED[i] * (1.0 + SPEED_RATIO[i]);
+        i += 1;
+    }
+    speed
+};
+
 // Constants from the Java class
 pub struct Calculate {
     // Constants
@@ -75,7 +109,6 @@ pub struct Calculate {
     full_zodiac: Vec<String>,
     zodiac_name: Vec<String>,
     speed_state: Vec<String>,
-    mountain_name: Vec<String>,
     house_system_char: Vec<String>,
     correction_key: String,
     equatorial_orbit: bool,
@@ -139,10 +172,10 @@ impl Calculate {
             sidereal_mode: false,
             sun_pos: 0.0,
             moon_pos: 0.0,
-            stationary_gap: vec![],
-            invisible_gap: vec![],
-            slow_speed: vec![],
-            fast_speed: vec![],
+            stationary_gap: STATIONARY_GAP.to_vec(),
+            invisible_gap: INVISIBLE_GAP.to_vec(),
+            slow_speed: SLOW_SPEED.to_vec(),
+            fast_speed: FAST_SPEED.to_vec(),
             location: [0.0; 3],
             computation: [0.0; 6],
             ascmc: [0.0; 10],
@@ -154,7 +187,6 @@ impl Calculate {
             full_zodiac: vec![],
             zodiac_name: vec![],
             speed_state: vec![],
-            mountain_name: vec![],
             house_system_char: vec![],
             correction_key: String::new(),
             equatorial_orbit: false,
@@ -180,19 +212,6 @@ impl Calculate {
                                 "CA".to_string(), "LE".to_string(), "VI".to_string(),
                                 "LI".to_string(), "SC".to_string(), "SA".to_string(),
                                 "CP".to_string(), "AQ".to_string(), "PI".to_string()];
-        self.mountain_name = vec!["1".to_string(), "2".to_string(), "3".to_string(),
-                                  "4".to_string(), "5".to_string(), "6".to_string(),
-                                  "7".to_string(), "8".to_string(), "9".to_string(),
-                                  "10".to_string(), "11".to_string(), "12".to_string(),
-                                  "13".to_string(), "14".to_string(), "15".to_string(),
-                                  "16".to_string(), "17".to_string(), "18".to_string(),
-                                  "19".to_string(), "20".to_string(), "21".to_string(),
-                                  "22".to_string(), "23".to_string(), "24".to_string()];
-
-        self.stationary_gap = vec![0.05, 0.1, 0.15]; // Sample values
-        self.invisible_gap = vec![5.0, 10.0, 15.0]; // Sample values
-        self.slow_speed = vec![0.1, 0.2, 0.3]; // Sample values
-        self.fast_speed = vec![1.1, 1.2, 1.3]; // Sample values
         self.correction_key = "fixstar_equ_adjustments".to_string();
 
         self.set_topocentric_mode(false, false);
@@ -527,14 +546,7 @@ impl Calculate {
     }
 
     fn normalize_degree(degree: f64) -> f64 {
-        let mut deg = degree;
-        while deg < 0.0 {
-            deg += 360.0;
-        }
-        while deg >= 360.0 {
-            deg -= 360.0;
-        }
-        deg
+        degree.rem_euclid(360.0)
     }
 
     fn compute_orbit(&mut self) -> f64 {
@@ -571,6 +583,64 @@ impl Calculate {
         if gap > 180.0 { 360.0 - gap } else { gap }
     }
 
+    /// 天体的行度状态：伏、留、逆、迟、疾或平常，依次判断
+    /// body 为 SE_SUN..SE_SATURN 以外者（如罗计）只分逆行与平常；elongation 为与太阳的角距（度）
+    pub fn get_speed_state(&self, body: i32, speed: f64, elongation: f64) -> i32 {
+        let Some(i) = usize::try_from(body).ok().filter(|&i| i < self.stationary_gap.len()) else {
+            return if speed < 0.0 { self.speed_reverse } else { self.speed_normal };
+        };
+        if elongation < self.invisible_gap[i] {
+            self.speed_invisible
+        } else if speed.abs() < self.stationary_gap[i] {
+            self.speed_stationary
+        } else if speed < 0.0 {
+            self.speed_reverse
+        } else if speed < self.slow_speed[i] {
+            self.speed_slow
+        } else if self.fast_speed[i] > 0.0 && speed > self.fast_speed[i] {
+            self.speed_fast
+        } else {
+            self.speed_normal
+        }
+    }
+
+    /// 设定某天体留的日行度范围（度/日），0 表示不判断留
+    pub fn set_stationary_gap(&mut self, body: i32, gap: f64) {
+        if let Some(slot) = usize::try_from(body).ok().and_then(|i| self.stationary_gap.get_mut(i)) {
+            *slot = gap;
+        }
+    }
+
+    /// 设定某天体顺行为迟的日行度上限（度/日），0 表示不判断迟
+    pub fn set_slow_speed(&mut self, body: i32, speed: f64) {
+        if let Some(slot) = usize::try_from(body).ok().and_then(|i| self.slow_speed.get_mut(i)) {
+            *slot = speed;
+        }
+    }
+
+    /// 设定某天体顺行为疾的日行度下限（度/日），0 表示不判断疾
+    pub fn set_fast_speed(&mut self, body: i32, speed: f64) {
+        if let Some(slot) = usize::try_from(body).ok().and_then(|i| self.fast_speed.get_mut(i)) {
+            *slot = speed;
+        }
+    }
+
+    /// 设定某天体伏的角距（度），0 表示不判断伏
+    pub fn set_invisible_gap(&mut self, body: i32, gap: f64) {
+        if let Some(slot) = usize::try_from(body).ok().and_then(|i| self.invisible_gap.get_mut(i)) {
+            *slot = gap;
+        }
+    }
+
+    /// 以 transit_inc 为扫描步长、newton_max_iter 为迭代上限、time_precision 为精度的搜索参数
+    pub fn get_search_options(&self) -> SearchOptions {
+        SearchOptions {
+            step: self.transit_inc,
+            max_iter: self.newton_max_iter as usize,
+            precision: self.time_precision,
+        }
+    }
+
     pub fn get_speed_state_name(&self, state: i32, blank: &str) -> String {
         if state == self.speed_normal { blank.to_string() } else { self.speed_state[state as usize].clone() }
     }
@@ -715,6 +785,9 @@ impl Calculate {
         self.day_fortune_mode = day || false; // night_fortune_mode == 0
     }
 
+    /// 相位矩阵：aspects[i][j] 为 f_pos[i] 与 t_pos[j] 所成相位在 aspects_degree 中的序号加一，0 为无相位
+    /// f_pos 与 t_pos 为同一切片时跳过自身
+    #[deprecated(note = "用 calendar::aspect::aspects_within（同一组）或 aspects_between（两组），可得相位种类、入相出相与精确时刻")]
     pub fn compute_aspects(&self, f_pos: &[f64], t_pos: &[f64], 
                           aspects_degree: &[f64], aspects_tolerance: &[f64]) -> Vec<Vec<i32>> {
         let mut aspects = vec![vec![0; t_pos.len()]; f_pos.len()];
@@ -757,18 +830,15 @@ impl Calculate {
         }
     }
 
-    pub fn get_mountain(&self, degree: f64) -> String {
-        let mut val = degree - 22.5 + self.mountain_offset;
-        if val < 0.0 {
-            val += 360.0;
-        }
-        let index = (val / 15.0) as usize;
-        let val = 15.0 + (index as f64) * 15.0 - val;
-        if index < self.mountain_name.len() {
-            self.mountain_name[index].clone()
-        } else {
-            "Unknown".to_string()
-        }
+    /// 方位角（加上 set_mountain_offset 设置的偏移）所在的二十四山及在该山内的度数偏移（0..15）
+    pub fn get_mountain(&self, degree: f64) -> (Mountain, f64) {
+        Mountain::from_degree(degree + self.mountain_offset)
+    }
+
+    /// 方位角（加上偏移）在罗盘各层的读数，度数不是有限数时为 None
+    pub fn get_luopan(&self, degree: f64) -> Option<LuopanReading> {
+        let degree = degree + self.mountain_offset;
+        degree.is_finite().then(|| LuopanReading::at(Self::normalize_degree(degree)))
     }
 
     pub fn get_elemental_index(&self, degree: f64) -> i32 {
@@ -817,5 +887,44 @@ mod tests {
     fn test_degree_normalization() {
         assert_eq!(Calculate::normalize_degree(370.0), 10.0);
         assert_eq!(Calculate::normalize_degree(-10.0), 350.0);
+        assert_eq!(Calculate::normalize_degree(1e300), 1e300 % 360.0);
+        let calc = Calculate::new();
+        assert!(calc.get_luopan(f64::INFINITY).is_none());
+        assert!(calc.get_luopan(f64::NAN).is_none());
+        assert_eq!(calc.get_luopan(-365.0).unwrap().mountain, Mountain::Zi);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_compute_aspects() {
+        // 同一切片自比时跳过自身，0°与90°相刑
+        let calc = Calculate::new();
+        let positions = [0.0, 90.0];
+        let aspects = calc.compute_aspects(&positions, &positions, &[0.0, 90.0], &[8.0, 6.0]);
+        assert_eq!(aspects, [[0, 2], [2, 0]]);
+        let other = positions;
+        assert_eq!(calc.compute_aspects(&positions, &other, &[0.0, 90.0], &[8.0, 6.0]), [[1, 2], [2, 1]]);
+    }
+
+    #[test]
+    fn test_speed_state() {
+        let mut calc = Calculate::new();
+        assert_eq!(calc.get_speed_state(SE_SATURN, -0.05, 120.0), calc.speed_reverse);
+        assert_eq!(calc.get_speed_state(SE_SATURN, 0.002, 120.0), calc.speed_stationary);
+        assert_eq!(calc.get_speed_state(SE_SATURN, 0.12, 10.0), calc.speed_invisible);
+        assert_eq!(calc.get_speed_state(SE_MARS, 0.3, 90.0), calc.speed_slow);
+        assert_eq!(calc.get_speed_state(SE_MOON, 15.0, 90.0), calc.speed_fast);
+        assert_eq!(calc.get_speed_state(SE_SUN, 0.99, 0.0), calc.speed_normal);
+        assert_eq!(calc.get_speed_state(SE_MEAN_NODE, -0.05, 0.0), calc.speed_reverse);
+        calc.set_stationary_gap(SE_SATURN, 0.0);
+        assert_eq!(calc.get_speed_state(SE_SATURN, 0.002, 120.0), calc.speed_slow);
+        // 迟疾由平行按比例增减：火星平行0.524°，一成为0.0524°
+        assert!((SLOW_SPEED[SE_MARS as usize] - 0.4716).abs() < 1e-9);
+        assert!((FAST_SPEED[SE_MARS as usize] - 0.5764).abs() < 1e-9);
+        calc.set_slow_speed(SE_MARS, 0.0);
+        calc.set_fast_speed(SE_MOON, 0.0);
+        assert_eq!(calc.get_speed_state(SE_MARS, 0.3, 90.0), calc.speed_normal);
+        assert_eq!(calc.get_speed_state(SE_MOON, 15.0, 90.0), calc.speed_normal);
+        assert_eq!(calc.get_search_options(), SearchOptions::default());
     }
 }
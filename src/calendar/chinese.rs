@@ -1,3 +1,4 @@
+use alloc::boxed::Box;
 use serde::Serialize;
 use crate::concepts::gan_zhi::GanZhi;
 
@@ -10,7 +11,7 @@ pub struct DateTime {
 }
 
 impl DateTime {
-    pub fn from_solar_date(year: i32, month: u32, day: u32, hour: u32) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_solar_date(year: i32, month: u32, day: u32, hour: u32) -> Result<Self, Box<dyn core::error::Error>> {
         // TODO: 实现从公历到干支的转换算法
         // 目前返回占位符值
         let year_gan_zhi = GanZhi::new(
@@ -1,9 +1,27 @@
 // 使用新的安全 Swiss Ephemeris 包装器
-use crate::calendar::swisseph::{SwissEph, SE_SUN, SEFLG_SWIEPH, SEFLG_SIDEREAL};
+use serde::Serialize;
+use crate::calendar::swisseph::{SwissEph, SE_SUN, SEFLG_SWIEPH, SEFLG_SIDEREAL, SEFLG_SPEED, SEFLG_EQUATORIAL, SE_HOUSE_EQUAL};
+
+/// 随库附带的星历文件目录，可用环境变量 SE_EPHE_PATH 覆盖
+pub const EPHE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/ephe");
 
 #[derive(Debug)]
 pub struct EphemerisError(String);
 
+impl std::fmt::Display for EphemerisError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for EphemerisError {}
+
+impl EphemerisError {
+    pub(crate) fn new(message: String) -> Self {
+        EphemerisError(message)
+    }
+}
+
 impl From<crate::calendar::swisseph::SwissEphError> for EphemerisError {
     fn from(err: crate::calendar::swisseph::SwissEphError) -> Self {
         EphemerisError(format!("{}", err))
@@ -16,9 +34,78 @@ pub fn solar_longitude(julian_day: f64) -> Result<f64, EphemerisError> {
     Ok(longitude)
 }
 
+/// 回归黄道上的地心视位置
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct EclipticPosition {
+    /// 黄经（度）
+    pub longitude: f64,
+    /// 黄纬（度）
+    pub latitude: f64,
+    /// 地心距离（AU）
+    pub distance: f64,
+    /// 黄经速度（度/日），负值为逆行
+    pub longitude_speed: f64,
+    /// 黄纬速度（度/日）
+    pub latitude_speed: f64,
+    /// 距离变化速度（AU/日）
+    pub distance_speed: f64,
+}
+
+/// 打开星历并指向星历文件目录
+/// 每次释放句柄都会调用 swe_close 清空星历缓存，一次排盘中的多次计算应共用同一句柄
+pub fn open() -> Result<SwissEph, EphemerisError> {
+    let eph = SwissEph::new()?;
+    let path = std::env::var("SE_EPHE_PATH").unwrap_or_else(|_| EPHE_PATH.to_string());
+    eph.set_ephe_path(path)?;
+    Ok(eph)
+}
+
+/// 计算天体在世界时儒略日的回归黄道位置与速度
+pub fn body_position(eph: &SwissEph, julian_day_ut: f64, body: i32) -> Result<EclipticPosition, EphemerisError> {
+    let (longitude, latitude, distance, longitude_speed, latitude_speed, distance_speed) =
+        eph.calc_ut(julian_day_ut, body, SEFLG_SWIEPH | SEFLG_SPEED)?;
+    Ok(EclipticPosition {
+        longitude,
+        latitude,
+        distance,
+        longitude_speed,
+        latitude_speed,
+        distance_speed,
+    })
+}
+
+/// 恒星在世界时儒略日的视位置，返回（经度, 纬度）：equatorial 为真时为赤经、赤纬，否则为回归黄道的黄经、黄纬
+/// star 为 ephe/sefstars.txt 中的传统名，或以逗号开头的拜耳名（如 ",alVir"）
+pub fn fixed_star_position(eph: &SwissEph, julian_day_ut: f64, star: &str, equatorial: bool) -> Result<(f64, f64), EphemerisError> {
+    let flags = if equatorial { SEFLG_SWIEPH | SEFLG_EQUATORIAL } else { SEFLG_SWIEPH };
+    let (longitude, latitude, _, _, _, _) = eph.fixstar_ut(star, julian_day_ut, flags)?;
+    Ok((longitude, latitude))
+}
+
+/// 世界时儒略日在某地的上升点与中天黄经（度），longitude 东经为正、latitude 北纬为正
+pub fn ascendant(eph: &SwissEph, julian_day_ut: f64, longitude: f64, latitude: f64) -> Result<(f64, f64), EphemerisError> {
+    // 上升点与中天不随分宫制而变，等宫制在高纬度也能求出
+    let (_, ascmc) = eph.houses(julian_day_ut, latitude, longitude, SE_HOUSE_EQUAL)?;
+    Ok((ascmc[0], ascmc[1]))
+}
+
+/// 天体在世界时儒略日与太阳的角距（度，0..180），太阳自身为0
+pub fn elongation(eph: &SwissEph, julian_day_ut: f64, body: i32) -> Result<f64, EphemerisError> {
+    let (_, _, elongation, _, _) = eph.pheno_ut(julian_day_ut, body, SEFLG_SWIEPH)?;
+    Ok(elongation)
+}
+
+/// 某一恒星黄道模式（SE_SIDM_*）在世界时儒略日的岁差（度），回归黄经减去此值即为恒星黄经
+pub fn ayanamsa(eph: &SwissEph, julian_day_ut: f64, sidereal_mode: i32) -> Result<f64, EphemerisError> {
+    eph.set_sidereal_mode(sidereal_mode, 0.0, 0.0);
+    let julian_day_et = julian_day_ut + eph.delta_t(julian_day_ut);
+    Ok(eph.get_ayanamsa(julian_day_et)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::calendar::swisseph::{SE_SIDM_LAHIRI, SE_VENUS};
 
     #[test]
     fn test_solar_longitude() {
@@ -36,4 +123,43 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_body_position() {
+        let eph = open().unwrap();
+        // 2000年1月1日12时（世界时）太阳黄经约280.37°，日行约1.02°
+        let sun = body_position(&eph, 2451545.0, SE_SUN).unwrap();
+        assert!((sun.longitude - 280.37).abs() < 0.05);
+        assert!((sun.longitude_speed - 1.02).abs() < 0.01);
+        assert!(sun.latitude.abs() < 0.01);
+    }
+
+    #[test]
+    fn test_fixed_star_position() {
+        let eph = open().unwrap();
+        // J2000.0 角宿一（Spica）黄经约203.84°，赤经约201.30°
+        let (longitude, latitude) = fixed_star_position(&eph, 2451545.0, ",alVir", false).unwrap();
+        assert!((longitude - 203.84).abs() < 0.02);
+        assert!((latitude + 2.05).abs() < 0.02);
+        let (ascension, _) = fixed_star_position(&eph, 2451545.0, "Spica", true).unwrap();
+        assert!((ascension - 201.30).abs() < 0.02);
+        assert!(fixed_star_position(&eph, 2451545.0, ",noSuchStar", false).is_err());
+    }
+
+    #[test]
+    fn test_elongation() {
+        let eph = open().unwrap();
+        // 2000年1月1日12时（世界时）金星为晨星，距日约38.85°；太阳自身为0
+        let venus = elongation(&eph, 2451545.0, SE_VENUS).unwrap();
+        assert!((venus - 38.85).abs() < 0.05);
+        assert_eq!(elongation(&eph, 2451545.0, SE_SUN).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_ayanamsa() {
+        let eph = open().unwrap();
+        // J2000.0 Lahiri 岁差约23.86°
+        let value = ayanamsa(&eph, 2451545.0, SE_SIDM_LAHIRI).unwrap();
+        assert!((value - 23.86).abs() < 0.01);
+    }
+}
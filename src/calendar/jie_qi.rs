@@ -0,0 +1,102 @@
+// src/calendar/jie_qi.rs - 节气纪时
+// 以立春交节为岁首的年与年干支，北京时间的日干支，以冬至、夏至前后最近的甲子日起阴阳遁，并据此推日家、时家紫白
+use crate::calendar::ephemeris::EphemerisError;
+use crate::calendar::lunar;
+use crate::calendar::search::{self, Boundaries, SearchOptions};
+use crate::calendar::swisseph::SE_SUN;
+use crate::concepts::gan_zhi::GanZhi;
+use crate::concepts::nine_star::{Dun, NineStar};
+use crate::concepts::traits::Index;
+
+/// 立春的太阳视黄经（度）
+pub const LI_CHUN: f64 = 315.0;
+
+// 交节时刻所在的公历年；只用于远离年界的立春、冬至，按平均回归年估算即可
+fn gregorian_year(julian_day: f64) -> i32 {
+    ((julian_day - 2451544.5) / 365.2425).floor() as i32 + 2000
+}
+
+/// 世界时儒略日所属的节气年：立春交节时刻之前仍属上一年
+pub fn year(julian_day: f64) -> Result<i32, EphemerisError> {
+    let found = search::crossings(SE_SUN, &Boundaries::Longitude(LI_CHUN), julian_day - 370.0, julian_day, false, &SearchOptions::default())?;
+    let li_chun = found
+        .last()
+        .ok_or_else(|| EphemerisError::new(format!("儒略日 {} 之前一年内找不到立春", julian_day)))?;
+    Ok(gregorian_year(li_chun.julian_day))
+}
+
+/// 以立春为岁首的年干支
+pub fn year_gan_zhi(julian_day: f64) -> Result<GanZhi, EphemerisError> {
+    Ok(GanZhi::from_index((year(julian_day)? - 4).rem_euclid(60) as usize + 1))
+}
+
+/// 北京时间所在之日的日干支，23时后的子时仍算当日
+pub fn day_gan_zhi(julian_day: f64) -> GanZhi {
+    // 儒略日数加49后除60的余数为0者为甲子日
+    GanZhi::from_index(1) + (lunar::civil_day(julian_day) as i64 + 49)
+}
+
+/// 日家起遁：冬至前后最近的甲子日起阳遁，夏至前后最近的甲子日起阴遁
+/// 返回所在的遁及起遁甲子日（以儒略日数表示）
+pub fn dun(julian_day: f64) -> Result<(Dun, f64), EphemerisError> {
+    let today = lunar::civil_day(julian_day);
+    // 下一个起遁日可能早于二至约30日，故向后多查一段
+    let found = search::crossings(SE_SUN, &Boundaries::Custom(&[270.0, 90.0]), julian_day - 220.0, julian_day + 40.0, false, &SearchOptions::default())?;
+    found
+        .iter()
+        .rev()
+        .map(|solstice| {
+            let day = lunar::civil_day(solstice.julian_day);
+            let since = (day as i64 + 49).rem_euclid(60) as f64;
+            let start = if since <= 30.0 { day - since } else { day + 60.0 - since };
+            let dun = if solstice.boundary == 0 { Dun::Yang } else { Dun::Yin };
+            (dun, start)
+        })
+        .find(|(_, start)| *start <= today)
+        .ok_or_else(|| EphemerisError::new(format!("儒略日 {} 之前找不到起遁的甲子日", julian_day)))
+}
+
+/// 世界时儒略日的日家紫白，按北京时间定日
+pub fn day_star(julian_day: f64) -> Result<NineStar, EphemerisError> {
+    let (dun, start) = dun(julian_day)?;
+    Ok(NineStar::of_day(dun, (lunar::civil_day(julian_day) - start) as u32))
+}
+
+/// 世界时儒略日的时家紫白，阴阳遁从日家
+pub fn hour_star(julian_day: f64) -> Result<NineStar, EphemerisError> {
+    let (dun, _) = dun(julian_day)?;
+    Ok(NineStar::of_hour(dun, *day_gan_zhi(julian_day).branch(), lunar::hour_branch(julian_day)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::concepts::traits::ChineseName;
+
+    // 某儒略日数当日北京时间正午的世界时儒略日
+    fn noon(day: f64) -> f64 {
+        day - 0.5 + 4.0 / 24.0
+    }
+
+    #[test]
+    fn test_year() {
+        // 2024年立春在2月4日16时27分（北京时间）：当日午时仍属癸卯年，次日为甲辰年
+        assert_eq!(year(noon(2460345.0)).unwrap(), 2023);
+        assert_eq!(year_gan_zhi(noon(2460346.0)).unwrap().chinese_name(), "甲辰");
+        assert_eq!(day_gan_zhi(noon(2451545.0)).chinese_name(), "戊午");
+    }
+
+    #[test]
+    fn test_day_star() {
+        // 2023年夏至后最近的甲子日为7月5日，起阴遁九紫；冬至在12月22日，其后最近的甲子日为2024年1月1日，起阳遁一白
+        assert_eq!(dun(noon(2460131.0)).unwrap(), (Dun::Yin, 2460131.0));
+        assert_eq!(day_gan_zhi(noon(2460311.0)).chinese_name(), "甲子");
+        // 冬至已过、未到甲子日，仍为阴遁：12月25日距起遁173日，九紫逆行两步为七赤
+        assert_eq!(dun(noon(2460304.0)).unwrap().0, Dun::Yin);
+        assert_eq!(day_star(noon(2460304.0)).unwrap(), NineStar::QiChi);
+        assert_eq!(day_star(noon(2460311.0)).unwrap(), NineStar::YiBai);
+        assert_eq!(day_star(noon(2460312.0)).unwrap(), NineStar::ErHei);
+        // 阳遁子日午时：子时一白起，顺行至午为七赤
+        assert_eq!(hour_star(noon(2460311.0)).unwrap(), NineStar::QiChi);
+    }
+}
@@ -0,0 +1,167 @@
+// src/calendar/lunar.rs - 农历日期
+// 年、月（含闰月）、日的农历日期；启用 ephemeris 时可由儒略日按定朔与无中气置闰之法换算
+use alloc::format;
+#[cfg(feature = "ephemeris")]
+use alloc::vec::Vec;
+use serde::Serialize;
+use crate::concepts::gan_zhi::{EarthlyBranch, GanZhi};
+use crate::concepts::traits::Index;
+use crate::json::MingbuError;
+#[cfg(feature = "ephemeris")]
+use crate::calendar::ephemeris::{self, EphemerisError};
+#[cfg(feature = "ephemeris")]
+use crate::calendar::search::{self, Boundaries, SearchOptions};
+#[cfg(feature = "ephemeris")]
+use crate::calendar::swisseph::{SwissEph, SE_MOON, SE_SUN, SEFLG_SPEED, SEFLG_SWIEPH};
+
+/// 农历换算所用的时区：东八区，以北京时间定日
+pub const CHINA_OFFSET: f64 = 8.0 / 24.0;
+
+/// 农历日期
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LunarDate {
+    /// 农历年，以该年正月所在的公历年计
+    pub year: i32,
+    /// 月（1..=12），闰月与所闰之月同数
+    pub month: u32,
+    /// 是否为闰月
+    pub leap: bool,
+    /// 日（1..=30）
+    pub day: u32,
+}
+
+impl LunarDate {
+    /// 由年、月、日建立农历日期，月须在1..=12、日须在1..=30之间
+    pub fn new(year: i32, month: u32, leap: bool, day: u32) -> Result<Self, MingbuError> {
+        if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+            return Err(MingbuError {
+                code: "CALENDAR_ERROR",
+                message: format!("农历{}月{}日不存在", month, day),
+            });
+        }
+        Ok(LunarDate { year, month, leap, day })
+    }
+
+    /// 年干支，以正月初一为岁首
+    pub fn year_gan_zhi(&self) -> GanZhi {
+        GanZhi::from_index((self.year - 4).rem_euclid(60) as usize + 1)
+    }
+
+    /// 年支
+    pub fn year_branch(&self) -> EarthlyBranch {
+        *self.year_gan_zhi().branch()
+    }
+
+    /// 月建之支：正月建寅、二月建卯……十二月建丑，闰月从所闰之月
+    pub fn month_branch(&self) -> EarthlyBranch {
+        EarthlyBranch::from_index(self.month as usize % 12 + 2)
+    }
+}
+
+/// 世界时儒略日在北京时间所属的日期，以该日正午的儒略日（儒略日数）表示
+#[cfg(feature = "ephemeris")]
+pub fn civil_day(julian_day: f64) -> f64 {
+    (julian_day + 0.5 + CHINA_OFFSET).floor()
+}
+
+/// 世界时儒略日在北京时间所属的时辰，23时至1时为子时
+#[cfg(feature = "ephemeris")]
+pub fn hour_branch(julian_day: f64) -> EarthlyBranch {
+    let hours = (julian_day + 0.5 + CHINA_OFFSET - civil_day(julian_day)) * 24.0;
+    EarthlyBranch::from_index(((hours + 1.0) / 2.0) as usize % 12 + 1)
+}
+
+// 世界时 [from, to] 内各次合朔（日月黄经相同）的时刻
+#[cfg(feature = "ephemeris")]
+fn new_moons(eph: &SwissEph, from: f64, to: f64, options: &SearchOptions) -> Result<Vec<f64>, EphemerisError> {
+    let mut elongation = |t: f64| -> Result<(f64, f64), EphemerisError> {
+        let (moon, _, _, moon_speed, _, _) = eph.calc_ut(t, SE_MOON, SEFLG_SWIEPH | SEFLG_SPEED)?;
+        let (sun, _, _, sun_speed, _, _) = eph.calc_ut(t, SE_SUN, SEFLG_SWIEPH | SEFLG_SPEED)?;
+        Ok((search::wrap(moon - sun), moon_speed - sun_speed))
+    };
+    // 望时角距在 ±180° 处回绕，两端都远离0°的变号不是合朔
+    let found = search::brackets(&mut |t: f64| elongation(t).map(|(value, _)| value), from, to, options.step, 90.0)?;
+    found
+        .into_iter()
+        .map(|(lo, hi, f_lo)| search::find_root(&mut elongation, lo, hi, f_lo, options))
+        .collect()
+}
+
+#[cfg(feature = "ephemeris")]
+impl LunarDate {
+    /// 世界时儒略日所在的农历日期：合朔之日为初一，冬至所在之月为十一月，
+    /// 两个十一月之间有十三个月时，第一个不含中气的月为闰月
+    pub fn from_julian_day(julian_day: f64) -> Result<Self, EphemerisError> {
+        let options = SearchOptions::default();
+        let eph = ephemeris::open()?;
+        let today = civil_day(julian_day);
+        // 中气即太阳黄经30°的整数倍，第9界（270°）为冬至
+        let terms = search::crossings(SE_SUN, &Boundaries::Signs, julian_day - 400.0, julian_day + 400.0, false, &options)?;
+        let moons: Vec<f64> = new_moons(&eph, julian_day - 430.0, julian_day + 430.0, &options)?
+            .into_iter()
+            .map(civil_day)
+            .collect();
+        // 十一月的朔日：不晚于冬至当日的最后一个朔日
+        let month_eleven = |solstice: f64| moons.iter().copied().rev().find(|day| *day <= civil_day(solstice));
+        let solstices: Vec<f64> = terms.iter().filter(|term| term.boundary == 9).map(|term| term.julian_day).collect();
+        let (solstice, start, end) = solstices
+            .windows(2)
+            .find_map(|pair| {
+                let (start, end) = (month_eleven(pair[0])?, month_eleven(pair[1])?);
+                (start <= today && today < end).then_some((pair[0], start, end))
+            })
+            .ok_or_else(|| EphemerisError::new(format!("儒略日 {} 附近找不到两次冬至", julian_day)))?;
+
+        // 一岁之中各月的朔日，末尾补上下一个十一月的朔日作为界
+        let mut months: Vec<f64> = moons.iter().copied().filter(|day| (start..end).contains(day)).collect();
+        months.push(end);
+        let has_zhong_qi = |i: usize| terms.iter().any(|term| (months[i]..months[i + 1]).contains(&civil_day(term.julian_day)));
+        let leap_index = if months.len() == 14 { (0..13).find(|i| !has_zhong_qi(*i)) } else { None };
+
+        let mut month = 11;
+        for i in 0..months.len() - 1 {
+            let leap = Some(i) == leap_index;
+            if i > 0 && !leap {
+                month = month % 12 + 1;
+            }
+            if today < months[i + 1] {
+                // 冬至在十二月下旬，据此定所在公历年；正月以后属下一个农历年
+                let year = ((solstice - 2451544.5) / 365.2425).floor() as i32 + 2000;
+                let year = if month >= 11 { year } else { year + 1 };
+                return Ok(LunarDate { year, month, leap, day: (today - months[i]) as u32 + 1 });
+            }
+        }
+        Err(EphemerisError::new(format!("儒略日 {} 不在所求的一岁之中", julian_day)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::concepts::traits::ChineseName;
+
+    #[test]
+    fn test_lunar_date() {
+        let date = LunarDate::new(2024, 1, false, 1).unwrap();
+        assert_eq!(date.year_gan_zhi().chinese_name(), "甲辰");
+        assert_eq!(date.month_branch(), EarthlyBranch::Yin);
+        assert_eq!(LunarDate::new(2023, 12, false, 1).unwrap().month_branch(), EarthlyBranch::Chou);
+        assert!(LunarDate::new(2024, 13, false, 1).is_err());
+        assert!(LunarDate::new(2024, 1, false, 31).is_err());
+    }
+
+    #[cfg(feature = "ephemeris")]
+    #[test]
+    fn test_from_julian_day() {
+        // 2024年2月10日（北京时间正午）为甲辰年正月初一，前一日为癸卯年腊月三十
+        let date = LunarDate::from_julian_day(2460350.5 + 4.0 / 24.0).unwrap();
+        assert_eq!(date, LunarDate { year: 2024, month: 1, leap: false, day: 1 });
+        let date = LunarDate::from_julian_day(2460349.5 + 4.0 / 24.0).unwrap();
+        assert_eq!(date, LunarDate { year: 2023, month: 12, leap: false, day: 30 });
+        // 2023年闰二月初一为公历3月22日，4月1日为闰二月十一
+        let date = LunarDate::from_julian_day(2460035.5 + 4.0 / 24.0).unwrap();
+        assert_eq!(date, LunarDate { year: 2023, month: 2, leap: true, day: 11 });
+        assert_eq!(hour_branch(2460035.5 + 4.0 / 24.0), EarthlyBranch::Wu);
+        assert_eq!(hour_branch(2460035.5 + 15.5 / 24.0), EarthlyBranch::Zi);
+    }
+}
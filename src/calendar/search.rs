@@ -0,0 +1,420 @@
+// src/calendar/search.rs - 天象时刻搜索
+// 以 SwissEph::calc_ut 的黄经与黄经速度为函数：先按步长扫描定出变号区间，再以牛顿法（越出区间时二分）求精确时刻；
+// 日、月及日心黄经的入界时刻直接用 swe_solcross_ut、swe_mooncross_ut、swe_helio_cross_ut
+use serde::Serialize;
+use crate::calendar::ephemeris::{self, EphemerisError};
+use crate::calendar::swisseph::{SwissEph, SE_SUN, SE_MOON, SEFLG_SWIEPH, SEFLG_SPEED, SEFLG_EQUATORIAL};
+use crate::concepts::ci::{CiBoundary, CiStation};
+use crate::concepts::xiu::{MansionFrame, MansionTable};
+
+/// 求逆行前后影时向前、向后查找的最大日数，足以覆盖冥王星的一次逆行
+pub const SHADOW_LIMIT: f64 = 400.0;
+
+/// 搜索参数，可由 Calculate::get_search_options 取得
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SearchOptions {
+    /// 扫描步长（日），须短于相邻两根的最小间隔
+    pub step: f64,
+    /// 求根的最大迭代次数
+    pub max_iter: usize,
+    /// 求根的时间精度（日）
+    pub precision: f64,
+}
+
+impl Default for SearchOptions {
+    /// 与 Calculate 的 transit_inc、newton_max_iter、time_precision 相同：步长1日，精度约1分钟
+    fn default() -> Self {
+        SearchOptions { step: 1.0, max_iter: 100, precision: 1.0 / (24.0 * 60.0 * 1.05) }
+    }
+}
+
+impl SearchOptions {
+    /// 检查参数：步长须为正的有限数，否则扫描不能前进；迭代次数须大于0，否则求不出根
+    pub fn validate(&self) -> Result<(), EphemerisError> {
+        check_step(self.step)?;
+        if self.max_iter == 0 {
+            return Err(EphemerisError::new("搜索的最大迭代次数须大于0".to_string()));
+        }
+        Ok(())
+    }
+}
+
+fn check_step(step: f64) -> Result<(), EphemerisError> {
+    if step.is_finite() && step > 0.0 {
+        Ok(())
+    } else {
+        Err(EphemerisError::new(format!("搜索步长须为正的有限数，而非 {}", step)))
+    }
+}
+
+/// 留转的方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum StationKind {
+    /// 由顺转逆
+    Retrograde,
+    /// 由逆转顺
+    Direct,
+}
+
+/// 留：黄经速度过零之时
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Station {
+    /// 世界时儒略日
+    pub julian_day: f64,
+    /// 留时的黄经（度）
+    pub longitude: f64,
+    /// 留后转逆或转顺
+    pub kind: StationKind,
+}
+
+/// 一次逆行及其前后影
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RetrogradePeriod {
+    /// 前影之始：顺行到达留顺点黄经之时
+    pub shadow_start: f64,
+    /// 留而转逆，逆行之始
+    pub start: Station,
+    /// 留而复顺，逆行之终
+    pub end: Station,
+    /// 后影之终：复顺后回到留逆点黄经之时
+    pub shadow_end: f64,
+}
+
+/// 经过黄经界的方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CrossingDirection {
+    /// 顺行越界，进入界后的区段
+    Direct,
+    /// 逆行退回界前的区段
+    Retrograde,
+}
+
+/// 一次越界
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Crossing {
+    /// 世界时儒略日
+    pub julian_day: f64,
+    /// 所经之界在界表中的序号（0 起）
+    pub boundary: usize,
+    /// 所经之界的黄经（度）
+    pub longitude: f64,
+    /// 顺行或逆行越界
+    pub direction: CrossingDirection,
+}
+
+impl Crossing {
+    /// 越界后所在区段的序号：顺行过第 i 界入第 i 段，逆行过第 i 界退入第 i-1 段；count 为界数
+    pub fn entered(&self, count: usize) -> usize {
+        match self.direction {
+            CrossingDirection::Direct => self.boundary,
+            CrossingDirection::Retrograde => (self.boundary + count - 1) % count,
+        }
+    }
+}
+
+/// 要搜索的黄经界，序号即所分区段的序号
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundaries<'a> {
+    /// 单一黄经（度）
+    Longitude(f64),
+    /// 黄道十二宫，自春分点起每30°一界
+    Signs,
+    /// 二十四节气，自春分起每15°一界
+    SolarTerms,
+    /// 二十八宿起界，序号同 XiuMansion::ALL；赤道宿度表按赤经搜索
+    Mansions(&'a MansionTable),
+    /// 十二次起界，序号同 CiStation::ALL
+    Stations(CiBoundary),
+    /// 任意一组黄经（度）
+    Custom(&'a [f64]),
+}
+
+impl Boundaries<'_> {
+    /// 各界的黄经（度）
+    pub fn longitudes(&self) -> Vec<f64> {
+        match self {
+            Boundaries::Longitude(longitude) => vec![longitude.rem_euclid(360.0)],
+            Boundaries::Signs => (0..12).map(|i| i as f64 * 30.0).collect(),
+            Boundaries::SolarTerms => (0..24).map(|i| i as f64 * 15.0).collect(),
+            Boundaries::Mansions(table) => table.starts.to_vec(),
+            Boundaries::Stations(boundary) => CiStation::ALL.iter().map(|station| station.start(*boundary)).collect(),
+            Boundaries::Custom(longitudes) => longitudes.iter().map(|longitude| longitude.rem_euclid(360.0)).collect(),
+        }
+    }
+
+    /// 各界是否为赤经，只有赤道宿度表如此
+    pub fn equatorial(&self) -> bool {
+        matches!(self, Boundaries::Mansions(table) if table.frame == MansionFrame::Equatorial)
+    }
+}
+
+// 世界时儒略日的黄经与黄经速度
+fn motion(eph: &SwissEph, body: i32, julian_day: f64) -> Result<(f64, f64), EphemerisError> {
+    coordinate(eph, body, julian_day, SEFLG_SWIEPH | SEFLG_SPEED)
+}
+
+// 按 flags 所定坐标求经度与经度速度，含 SEFLG_EQUATORIAL 时为赤经
+fn coordinate(eph: &SwissEph, body: i32, julian_day: f64, flags: i32) -> Result<(f64, f64), EphemerisError> {
+    let (longitude, _, _, speed, _, _) = eph.calc_ut(julian_day, body, flags)?;
+    Ok((longitude, speed))
+}
+
+// 角度差归一到 (-180, 180]
+pub(crate) fn wrap(degree: f64) -> f64 {
+    180.0 - (180.0 - degree).rem_euclid(360.0)
+}
+
+// 按步长扫描 [from, to]，返回 f 变号的区间（起, 止, 起点函数值）
+// 两端绝对值都超过 jump 的变号是角度回绕造成的跳变，不视为根
+pub(crate) fn brackets<F>(f: &mut F, from: f64, to: f64, step: f64, jump: f64) -> Result<Vec<(f64, f64, f64)>, EphemerisError>
+where
+    F: FnMut(f64) -> Result<f64, EphemerisError>,
+{
+    check_step(step)?;
+    let mut found = Vec::new();
+    let mut lo = from;
+    let mut f_lo = f(lo)?;
+    while lo < to {
+        let hi = (lo + step).min(to);
+        let f_hi = f(hi)?;
+        if (f_lo < 0.0) != (f_hi < 0.0) && (f_lo.abs() < jump || f_hi.abs() < jump) {
+            found.push((lo, hi, f_lo));
+        }
+        lo = hi;
+        f_lo = f_hi;
+    }
+    Ok(found)
+}
+
+// 在 [lo, hi] 内求根，f 返回（函数值, 导数），f_lo 为起点函数值；牛顿步越出区间时改用二分
+pub(crate) fn find_root<F>(f: &mut F, mut lo: f64, mut hi: f64, f_lo: f64, options: &SearchOptions) -> Result<f64, EphemerisError>
+where
+    F: FnMut(f64) -> Result<(f64, f64), EphemerisError>,
+{
+    options.validate()?;
+    let mut t = 0.5 * (lo + hi);
+    for _ in 0..options.max_iter {
+        let (value, slope) = f(t)?;
+        if value == 0.0 {
+            return Ok(t);
+        }
+        if (value < 0.0) == (f_lo < 0.0) {
+            lo = t;
+        } else {
+            hi = t;
+        }
+        let newton = t - value / slope;
+        let next = if newton > lo && newton < hi { newton } else { 0.5 * (lo + hi) };
+        if (next - t).abs() < options.precision {
+            return Ok(next);
+        }
+        t = next;
+    }
+    Ok(t)
+}
+
+/// 天体在世界时 [from, to] 内的各次留，按时间先后排列
+pub fn stations(body: i32, from: f64, to: f64, options: &SearchOptions) -> Result<Vec<Station>, EphemerisError> {
+    let eph = ephemeris::open()?;
+    let mut speed = |t: f64| motion(&eph, body, t).map(|(_, speed)| speed);
+    let found = brackets(&mut speed, from, to, options.step, f64::INFINITY)?;
+    // 速度对时间的导数以中心差分求得
+    let h = options.step * 0.01;
+    let mut acceleration = |t: f64| {
+        let (before, after) = (speed(t - h)?, speed(t + h)?);
+        Ok((speed(t)?, (after - before) / (2.0 * h)))
+    };
+    found
+        .into_iter()
+        .map(|(lo, hi, f_lo)| {
+            let julian_day = find_root(&mut acceleration, lo, hi, f_lo, options)?;
+            let (longitude, _) = motion(&eph, body, julian_day)?;
+            let kind = if f_lo > 0.0 { StationKind::Retrograde } else { StationKind::Direct };
+            Ok(Station { julian_day, longitude, kind })
+        })
+        .collect()
+}
+
+// 通用解法：按步长扫描地心经度一次，对每一界检查变号并求根，逆行退界与再次进入都能找到
+// flags 为 calc_ut 的标志，含 SEFLG_EQUATORIAL 时按赤经搜索
+fn longitude_crossings(eph: &SwissEph, body: i32, targets: &[f64], from: f64, to: f64, flags: i32, options: &SearchOptions) -> Result<Vec<Crossing>, EphemerisError> {
+    options.validate()?;
+    let mut found = Vec::new();
+    let mut lo = from;
+    let (mut longitude_lo, _) = coordinate(eph, body, lo, flags)?;
+    while lo < to {
+        let hi = (lo + options.step).min(to);
+        let (longitude_hi, _) = coordinate(eph, body, hi, flags)?;
+        for (boundary, &target) in targets.iter().enumerate() {
+            let (f_lo, f_hi) = (wrap(longitude_lo - target), wrap(longitude_hi - target));
+            // 两端都远离此界的变号是对冲点处的回绕
+            if (f_lo < 0.0) == (f_hi < 0.0) || f_lo.abs() > 90.0 || f_hi.abs() > 90.0 {
+                continue;
+            }
+            let mut offset = |t: f64| coordinate(eph, body, t, flags).map(|(longitude, speed)| (wrap(longitude - target), speed));
+            let julian_day = find_root(&mut offset, lo, hi, f_lo, options)?;
+            let direction = if f_lo < 0.0 { CrossingDirection::Direct } else { CrossingDirection::Retrograde };
+            found.push(Crossing { julian_day, boundary, longitude: target, direction });
+        }
+        lo = hi;
+        longitude_lo = longitude_hi;
+    }
+    found.sort_by(|a, b| a.julian_day.total_cmp(&b.julian_day));
+    Ok(found)
+}
+
+// 只会顺行者逐界向后查找：cross(界, 起始时刻) 返回起始时刻之后首次到达该界之时
+fn forward_crossings<F>(mut cross: F, targets: &[f64], from: f64, to: f64, options: &SearchOptions) -> Result<Vec<Crossing>, EphemerisError>
+where
+    F: FnMut(f64, f64) -> Result<f64, EphemerisError>,
+{
+    options.validate()?;
+    let mut found = Vec::new();
+    for (boundary, &target) in targets.iter().enumerate() {
+        let mut julian_day = cross(target, from)?;
+        while julian_day <= to {
+            found.push(Crossing { julian_day, boundary, longitude: target, direction: CrossingDirection::Direct });
+            julian_day = cross(target, julian_day + options.step)?;
+        }
+    }
+    found.sort_by(|a, b| a.julian_day.total_cmp(&b.julian_day));
+    Ok(found)
+}
+
+/// 天体在世界时 [from, to] 内经过各界的时刻，按时间先后排列
+/// 地心的日、月用 swe_solcross_ut、swe_mooncross_ut；heliocentric 为真时用 swe_helio_cross_ut（日心不逆行，不适用于日、月）；
+/// 其余按步长扫描求根，逆行退界记为 CrossingDirection::Retrograde，options.step 须短于相邻两次越界的间隔
+/// 赤道宿度表按地心赤经扫描（日、月亦然），不能与 heliocentric 同用
+pub fn crossings(body: i32, boundaries: &Boundaries, from: f64, to: f64, heliocentric: bool, options: &SearchOptions) -> Result<Vec<Crossing>, EphemerisError> {
+    let eph = ephemeris::open()?;
+    let targets = boundaries.longitudes();
+    if boundaries.equatorial() {
+        if heliocentric {
+            return Err(EphemerisError::new("赤道宿度表只能按地心赤经搜索".to_string()));
+        }
+        longitude_crossings(&eph, body, &targets, from, to, SEFLG_SWIEPH | SEFLG_SPEED | SEFLG_EQUATORIAL, options)
+    } else if heliocentric {
+        forward_crossings(|target, t| Ok(eph.helio_cross_ut(body, target, t, SEFLG_SWIEPH, 1)?), &targets, from, to, options)
+    } else if body == SE_SUN {
+        forward_crossings(|target, t| Ok(eph.solcross_ut(target, t, SEFLG_SWIEPH)?), &targets, from, to, options)
+    } else if body == SE_MOON {
+        forward_crossings(|target, t| Ok(eph.mooncross_ut(target, t, SEFLG_SWIEPH)?), &targets, from, to, options)
+    } else {
+        longitude_crossings(&eph, body, &targets, from, to, SEFLG_SWIEPH | SEFLG_SPEED, options)
+    }
+}
+
+/// 与世界时 [from, to] 有重叠的各次逆行及其前后影，按时间先后排列
+pub fn retrograde_periods(body: i32, from: f64, to: f64, options: &SearchOptions) -> Result<Vec<RetrogradePeriod>, EphemerisError> {
+    let found = stations(body, from - SHADOW_LIMIT, to + SHADOW_LIMIT, options)?;
+    let eph = ephemeris::open()?;
+    let mut periods = Vec::new();
+    for pair in found.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        if start.kind != StationKind::Retrograde || start.julian_day > to || end.julian_day < from {
+            continue;
+        }
+        // 前影取留逆之前最后一次到达留顺点，后影取留顺之后第一次回到留逆点
+        let flags = SEFLG_SWIEPH | SEFLG_SPEED;
+        let before = longitude_crossings(&eph, body, &[end.longitude], start.julian_day - SHADOW_LIMIT, start.julian_day, flags, options)?;
+        let after = longitude_crossings(&eph, body, &[start.longitude], end.julian_day, end.julian_day + SHADOW_LIMIT, flags, options)?;
+        if let (Some(shadow_start), Some(shadow_end)) = (before.last(), after.first()) {
+            periods.push(RetrogradePeriod { shadow_start: shadow_start.julian_day, start, end, shadow_end: shadow_end.julian_day });
+        }
+    }
+    Ok(periods)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::swisseph::{SE_MERCURY, SE_MARS};
+
+    #[test]
+    fn test_stations() {
+        // 2000年水星三次逆行：2月21日留于双鱼17°10′，3月14日复顺于双鱼2°47′，其余两次在巨蟹、天蝎
+        let options = SearchOptions::default();
+        let found = stations(SE_MERCURY, 2451544.5, 2451910.5, &options).unwrap();
+        assert_eq!(found.len(), 6);
+        assert!((found[0].julian_day - 2451596.03).abs() < 0.01);
+        assert!((found[0].longitude - 347.18).abs() < 0.01);
+        assert_eq!(found[0].kind, StationKind::Retrograde);
+        assert!((found[1].longitude - 332.78).abs() < 0.01);
+        assert_eq!(found[1].kind, StationKind::Direct);
+        assert!(stations(SE_SUN, 2451544.5, 2451910.5, &options).unwrap().is_empty());
+
+        let periods = retrograde_periods(SE_MERCURY, 2451544.5, 2451910.5, &options).unwrap();
+        assert_eq!(periods.len(), 3);
+        let period = periods[0];
+        assert!((period.start.julian_day - found[0].julian_day).abs() < options.precision);
+        assert!((period.end.julian_day - found[1].julian_day).abs() < options.precision);
+        let eph = ephemeris::open().unwrap();
+        // 前影之始的黄经即留顺点，后影之终的黄经即留逆点
+        assert!(period.shadow_start < period.start.julian_day && period.shadow_end > period.end.julian_day);
+        let (longitude, _) = motion(&eph, SE_MERCURY, period.shadow_start).unwrap();
+        assert!((longitude - period.end.longitude).abs() < 0.01);
+        let (longitude, _) = motion(&eph, SE_MERCURY, period.shadow_end).unwrap();
+        assert!((longitude - period.start.longitude).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_crossings() {
+        // 2000年太阳过二十四节气，春分在3月20日7时35分（世界时）
+        let options = SearchOptions::default();
+        let found = crossings(SE_SUN, &Boundaries::SolarTerms, 2451544.5, 2451910.5, false, &options).unwrap();
+        assert_eq!(found.len(), 24);
+        let equinox = found.iter().find(|crossing| crossing.boundary == 0).unwrap();
+        assert!((equinox.julian_day - 2451623.816).abs() < 0.001);
+
+        // 2000年11月水星逆行退出天蝎回到天秤，两日后复入天蝎
+        let found = crossings(SE_MERCURY, &Boundaries::Signs, 2451844.5, 2451874.5, false, &options).unwrap();
+        let directions: Vec<_> = found.iter().map(|crossing| (crossing.boundary, crossing.direction)).collect();
+        assert_eq!(directions, [(7, CrossingDirection::Retrograde), (7, CrossingDirection::Direct)]);
+        assert_eq!(found[0].entered(12), 6);
+        assert_eq!(found[1].entered(12), 7);
+        let eph = ephemeris::open().unwrap();
+        let (longitude, _) = motion(&eph, SE_MERCURY, found[0].julian_day).unwrap();
+        assert!(wrap(longitude - 210.0).abs() < 1e-4);
+
+        // 月亮约27.3日过春分点一次；日心火星只顺行
+        let found = crossings(SE_MOON, &Boundaries::Longitude(0.0), 2451544.5, 2451610.5, false, &options).unwrap();
+        assert_eq!(found.len(), 2);
+        assert!((found[1].julian_day - found[0].julian_day - 27.3).abs() < 0.5);
+        let found = crossings(SE_MARS, &Boundaries::Signs, 2451544.5, 2451910.5, true, &options).unwrap();
+        assert_eq!(found.len(), 7);
+        assert!(found.iter().all(|crossing| crossing.direction == CrossingDirection::Direct));
+        assert!(crossings(SE_SUN, &Boundaries::Signs, 2451544.5, 2451910.5, true, &options).is_err());
+    }
+
+    #[test]
+    fn test_equatorial_mansions() {
+        // 赤道宿度表按赤经搜索：2000年太阳赤经走一周，过二十八宿起界各一次，所过之界为太阳赤经而非黄经
+        let options = SearchOptions::default();
+        let table = MansionTable::ancient(MansionFrame::Equatorial, 201.30);
+        let found = crossings(SE_SUN, &Boundaries::Mansions(&table), 2451544.5, 2451909.5, false, &options).unwrap();
+        assert_eq!(found.len(), 28);
+        let eph = ephemeris::open().unwrap();
+        let jiao = found.iter().find(|crossing| crossing.boundary == 0).unwrap();
+        let (ascension, _) = coordinate(&eph, SE_SUN, jiao.julian_day, SEFLG_SWIEPH | SEFLG_SPEED | SEFLG_EQUATORIAL).unwrap();
+        assert!(wrap(ascension - 201.30).abs() < 1e-4);
+        let (longitude, _) = motion(&eph, SE_SUN, jiao.julian_day).unwrap();
+        assert!(wrap(longitude - 201.30).abs() > 1.0);
+        assert!(crossings(SE_MARS, &Boundaries::Mansions(&table), 2451544.5, 2451909.5, true, &options).is_err());
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        // 步长非正或非有限时扫描不能前进，迭代次数为0时求不出根，都应报错而非死循环
+        for step in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let options = SearchOptions { step, ..SearchOptions::default() };
+            assert!(options.validate().is_err());
+            assert!(crossings(SE_MARS, &Boundaries::Signs, 2451544.5, 2451910.5, false, &options).is_err());
+            assert!(crossings(SE_SUN, &Boundaries::Signs, 2451544.5, 2451910.5, false, &options).is_err());
+            assert!(stations(SE_MERCURY, 2451544.5, 2451910.5, &options).is_err());
+        }
+        let options = SearchOptions { max_iter: 0, ..SearchOptions::default() };
+        assert!(crossings(SE_MARS, &Boundaries::Signs, 2451544.5, 2451910.5, false, &options).is_err());
+        assert!(stations(SE_MERCURY, 2451544.5, 2451910.5, &options).is_err());
+        assert!(SearchOptions::default().validate().is_ok());
+    }
+}
@@ -68,26 +68,27 @@ pub const SE_PALLAS: c_int = 18;
 pub const SE_JUNO: c_int = 19;
 pub const SE_VESTA: c_int = 20;
 
-// Define flag constants
+// Define flag constants（与 swephexp.h 一致）
 pub const SEFLG_JPLEPH: c_int = 1;
 pub const SEFLG_SWIEPH: c_int = 2;
 pub const SEFLG_MOSEPH: c_int = 4;
-pub const SEFLG_TOPOCTR: c_int = 8;
-pub const SEFLG_SIDEREAL: c_int = 16;
-pub const SEFLG_ICRS: c_int = 32;
-pub const SEFLG_DPSIDEPS_1980: c_int = 64;
-pub const SEFLG_TRUEPOS: c_int = 128;
-pub const SEFLG_SPEED3: c_int = 256;
-pub const SEFLG_SPEED: c_int = 512;
-pub const SEFLG_NOGDEFL: c_int = 1024;
-pub const SEFLG_NOABERR: c_int = 2048;
-pub const SEFLG_ASTROMETRIC: c_int = 3072;
-pub const SEFLG_EQUATORIAL: c_int = 4096;
-pub const SEFLG_XYZ: c_int = 8192;
-pub const SEFLG_RADIANS: c_int = 16384;
-pub const SEFLG_BARYCTR: c_int = 32768;
-pub const SEFLG_HELCTR: c_int = 65536;
-pub const SEFLG_TRUEPOS_CORR: c_int = 409600;
+pub const SEFLG_HELCTR: c_int = 8;
+pub const SEFLG_TRUEPOS: c_int = 16;
+pub const SEFLG_J2000: c_int = 32;
+pub const SEFLG_NONUT: c_int = 64;
+pub const SEFLG_SPEED3: c_int = 128;
+pub const SEFLG_SPEED: c_int = 256;
+pub const SEFLG_NOGDEFL: c_int = 512;
+pub const SEFLG_NOABERR: c_int = 1024;
+pub const SEFLG_ASTROMETRIC: c_int = SEFLG_NOABERR | SEFLG_NOGDEFL;
+pub const SEFLG_EQUATORIAL: c_int = 2 * 1024;
+pub const SEFLG_XYZ: c_int = 4 * 1024;
+pub const SEFLG_RADIANS: c_int = 8 * 1024;
+pub const SEFLG_BARYCTR: c_int = 16 * 1024;
+pub const SEFLG_TOPOCTR: c_int = 32 * 1024;
+pub const SEFLG_SIDEREAL: c_int = 64 * 1024;
+pub const SEFLG_ICRS: c_int = 128 * 1024;
+pub const SEFLG_DPSIDEPS_1980: c_int = 256 * 1024;
 pub const SEFLG_TROPICAL: c_int = 0;
 
 // Define sidereal mode constants
@@ -262,6 +263,153 @@ impl SwissEph {
         }
     }
 
+    /// Calculate the position of a fixed star using Universal Time
+    /// The star is looked up in sefstars.txt by traditional name or by ",nomenclature" (e.g. ",alVir")
+    pub fn fixstar_ut(
+        &self,
+        star: &str,
+        julian_day_ut: f64,
+        flags: c_int,
+    ) -> SwissResult<(f64, f64, f64, f64, f64, f64)> {
+        // swe_fixstar2_ut writes the resolved star name back into this buffer
+        let mut star_buffer = [0u8; 256];
+        if star.len() >= star_buffer.len() || star.contains('\0') {
+            return Err(SwissEphError::InvalidInput("Invalid star name".to_string()));
+        }
+        star_buffer[..star.len()].copy_from_slice(star.as_bytes());
+        let mut position = [0.0; 6];
+        let mut error_msg = [0u8; 256];
+
+        let result = unsafe {
+            swe_fixstar2_ut(
+                star_buffer.as_mut_ptr() as *mut c_char,
+                julian_day_ut,
+                flags,
+                position.as_mut_ptr(),
+                error_msg.as_mut_ptr() as *mut c_char,
+            )
+        };
+
+        if result < 0 {
+            let error_cstr = unsafe { CStr::from_ptr(error_msg.as_ptr() as *const c_char) };
+            let error_str = error_cstr.to_string_lossy().into_owned();
+            Err(SwissEphError::CalculationFailed(error_str))
+        } else {
+            Ok((
+                position[0], // longitude or right ascension
+                position[1], // latitude or declination
+                position[2], // distance in AU
+                position[3], // longitude speed (deg/day)
+                position[4], // latitude speed (deg/day)
+                position[5], // distance speed (AU/day)
+            ))
+        }
+    }
+
+    /// Calculate planetary phenomena using Universal Time
+    /// Returns (phase angle, illuminated fraction, elongation from the Sun, apparent diameter, apparent magnitude)
+    pub fn pheno_ut(
+        &self,
+        julian_day_ut: f64,
+        body: c_int,
+        flags: c_int,
+    ) -> SwissResult<(f64, f64, f64, f64, f64)> {
+        // swe_pheno_ut requires room for 20 values
+        let mut attributes = [0.0; 20];
+        let mut error_msg = [0u8; 256];
+
+        let result = unsafe {
+            swe_pheno_ut(
+                julian_day_ut,
+                body,
+                flags,
+                attributes.as_mut_ptr(),
+                error_msg.as_mut_ptr() as *mut c_char,
+            )
+        };
+
+        if result < 0 {
+            let error_cstr = unsafe { CStr::from_ptr(error_msg.as_ptr() as *const c_char) };
+            let error_str = error_cstr.to_string_lossy().into_owned();
+            Err(SwissEphError::CalculationFailed(error_str))
+        } else {
+            Ok((
+                attributes[0], // phase angle (earth-planet-sun)
+                attributes[1], // phase (illuminated fraction of disc)
+                attributes[2], // elongation of planet
+                attributes[3], // apparent diameter of disc
+                attributes[4], // apparent magnitude
+            ))
+        }
+    }
+
+    /// Find the next time after julian_day_ut when the Sun's geocentric longitude reaches x2cross
+    pub fn solcross_ut(&self, x2cross: f64, julian_day_ut: f64, flags: c_int) -> SwissResult<f64> {
+        let mut error_msg = [0u8; 256];
+        let result = unsafe {
+            swe_solcross_ut(x2cross, julian_day_ut, flags, error_msg.as_mut_ptr() as *mut c_char)
+        };
+
+        // swe_solcross_ut signals failure with a result earlier than the start time
+        if result < julian_day_ut {
+            let error_cstr = unsafe { CStr::from_ptr(error_msg.as_ptr() as *const c_char) };
+            let error_str = error_cstr.to_string_lossy().into_owned();
+            Err(SwissEphError::CalculationFailed(error_str))
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Find the next time after julian_day_ut when the Moon's geocentric longitude reaches x2cross
+    pub fn mooncross_ut(&self, x2cross: f64, julian_day_ut: f64, flags: c_int) -> SwissResult<f64> {
+        let mut error_msg = [0u8; 256];
+        let result = unsafe {
+            swe_mooncross_ut(x2cross, julian_day_ut, flags, error_msg.as_mut_ptr() as *mut c_char)
+        };
+
+        // swe_mooncross_ut signals failure with a result earlier than the start time
+        if result < julian_day_ut {
+            let error_cstr = unsafe { CStr::from_ptr(error_msg.as_ptr() as *const c_char) };
+            let error_str = error_cstr.to_string_lossy().into_owned();
+            Err(SwissEphError::CalculationFailed(error_str))
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Find when a planet's heliocentric longitude reaches x2cross
+    /// Searches forward from julian_day_ut when dir >= 0, backward otherwise
+    pub fn helio_cross_ut(
+        &self,
+        body: c_int,
+        x2cross: f64,
+        julian_day_ut: f64,
+        flags: c_int,
+        dir: c_int,
+    ) -> SwissResult<f64> {
+        let mut julian_day_cross = 0.0;
+        let mut error_msg = [0u8; 256];
+        let result = unsafe {
+            swe_helio_cross_ut(
+                body,
+                x2cross,
+                julian_day_ut,
+                flags,
+                dir,
+                &mut julian_day_cross,
+                error_msg.as_mut_ptr() as *mut c_char,
+            )
+        };
+
+        if result < 0 {
+            let error_cstr = unsafe { CStr::from_ptr(error_msg.as_ptr() as *const c_char) };
+            let error_str = error_cstr.to_string_lossy().into_owned();
+            Err(SwissEphError::CalculationFailed(error_str))
+        } else {
+            Ok(julian_day_cross)
+        }
+    }
+
     /// Calculate houses
     pub fn houses(
         &self,
@@ -420,7 +568,8 @@ mod tests {
     fn test_constants() {
         assert_eq!(SE_SUN, 0);
         assert_eq!(SE_MOON, 1);
-        assert_eq!(SEFLG_SIDEREAL, 16);
+        assert_eq!(SEFLG_SIDEREAL, 65536);
+        assert_eq!(SEFLG_SPEED, 256);
     }
 
     #[test]
@@ -4,4 +4,12 @@ pub mod yinyang;
 pub mod shen_sha;
 pub mod zhang_sheng;
 pub mod bagua;
-pub mod traits;
\ No newline at end of file
+pub mod hexagram;
+pub mod direction;
+pub mod nine_star;
+pub mod mountain;
+pub mod luopan;
+pub mod xiu;
+pub mod ci;
+pub mod traits;
+pub mod parse;
\ No newline at end of file
@@ -1,8 +1,15 @@
-use serde::Serialize;
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
 use crate::concepts::yinyang::YinYang;
+use crate::concepts::wu_xing::WuXing;
+use crate::concepts::direction::Direction;
+use crate::concepts::traits::{ChineseName, Pinyin, Index, YinYangTrait, WuXingTrait};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::json;
 
 /// 八卦枚举，表示八种基本卦象
-#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Trigram {
     /// 乾（天）
     Qian,
@@ -22,6 +29,106 @@ pub enum Trigram {
     Kun,
 }
 
+/// 八卦所对应的家庭成员（乾坤生六子）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum FamilyRole {
+    /// 父
+    Father,
+    /// 母
+    Mother,
+    /// 长男
+    EldestSon,
+    /// 中男
+    MiddleSon,
+    /// 少男
+    YoungestSon,
+    /// 长女
+    EldestDaughter,
+    /// 中女
+    MiddleDaughter,
+    /// 少女
+    YoungestDaughter,
+}
+
+impl ChineseName for FamilyRole {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            FamilyRole::Father => "父",
+            FamilyRole::Mother => "母",
+            FamilyRole::EldestSon => "长男",
+            FamilyRole::MiddleSon => "中男",
+            FamilyRole::YoungestSon => "少男",
+            FamilyRole::EldestDaughter => "长女",
+            FamilyRole::MiddleDaughter => "中女",
+            FamilyRole::YoungestDaughter => "少女",
+        }
+    }
+}
+
+impl ChineseName for Trigram {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Trigram::Qian => "乾",
+            Trigram::Dui => "兑",
+            Trigram::Li => "离",
+            Trigram::Zhen => "震",
+            Trigram::Xun => "巽",
+            Trigram::Kan => "坎",
+            Trigram::Gen => "艮",
+            Trigram::Kun => "坤",
+        }
+    }
+}
+
+impl Pinyin for Trigram {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            Trigram::Qian => "qián",
+            Trigram::Dui => "duì",
+            Trigram::Li => "lí",
+            Trigram::Zhen => "zhèn",
+            Trigram::Xun => "xùn",
+            Trigram::Kan => "kǎn",
+            Trigram::Gen => "gèn",
+            Trigram::Kun => "kūn",
+        }
+    }
+}
+
+impl Index for Trigram {
+    /// 先天数：乾一、兑二、离三、震四、巽五、坎六、艮七、坤八
+    fn index(&self) -> usize {
+        *self as usize + 1
+    }
+
+    // 序号按8循环，0视为8（坤）
+    fn from_index(index: usize) -> Self {
+        Self::ALL[(index + 7) % 8]
+    }
+}
+
+impl YinYangTrait for Trigram {
+    /// 乾与三男为阳卦，坤与三女为阴卦
+    fn yinyang(&self) -> YinYang {
+        match self {
+            Trigram::Qian | Trigram::Zhen | Trigram::Kan | Trigram::Gen => YinYang::Yang,
+            Trigram::Kun | Trigram::Xun | Trigram::Li | Trigram::Dui => YinYang::Yin,
+        }
+    }
+}
+
+impl WuXingTrait for Trigram {
+    fn wuxing(&self) -> WuXing {
+        match self {
+            Trigram::Qian | Trigram::Dui => WuXing::Metal,
+            Trigram::Li => WuXing::Fire,
+            Trigram::Zhen | Trigram::Xun => WuXing::Wood,
+            Trigram::Kan => WuXing::Water,
+            Trigram::Gen | Trigram::Kun => WuXing::Earth,
+        }
+    }
+}
+
 impl Trigram {
     /// 获取卦象的三爻，从下到上
     pub fn lines(&self) -> [YinYang; 3] {
@@ -36,4 +143,193 @@ impl Trigram {
             Trigram::Kun => [YinYang::Yin, YinYang::Yin, YinYang::Yin],
         }
     }
-}
\ No newline at end of file
+
+    /// 由三爻得到卦象，从下到上
+    pub fn from_lines(lines: [YinYang; 3]) -> Self {
+        *Self::ALL.iter().find(|trigram| trigram.lines() == lines).unwrap()
+    }
+
+    /// 后天数，即洛书九宫的宫数：坎一、坤二、震三、巽四、乾六、兑七、艮八、离九（五为中宫，无卦）
+    pub fn hou_tian_index(&self) -> usize {
+        match self {
+            Trigram::Kan => 1,
+            Trigram::Kun => 2,
+            Trigram::Zhen => 3,
+            Trigram::Xun => 4,
+            Trigram::Qian => 6,
+            Trigram::Dui => 7,
+            Trigram::Gen => 8,
+            Trigram::Li => 9,
+        }
+    }
+
+    /// 由后天数（洛书宫数）得到卦象，5或超出1..=9时返回 None
+    pub fn from_hou_tian_index(index: usize) -> Option<Self> {
+        Self::ALL.iter().find(|trigram| trigram.hou_tian_index() == index).copied()
+    }
+
+    /// 先天八卦方位：乾南坤北，离东坎西，震东北、巽西南，艮西北、兑东南
+    pub fn xian_tian_direction(&self) -> Direction {
+        match self {
+            Trigram::Qian => Direction::South,
+            Trigram::Dui => Direction::SouthEast,
+            Trigram::Li => Direction::East,
+            Trigram::Zhen => Direction::NorthEast,
+            Trigram::Xun => Direction::SouthWest,
+            Trigram::Kan => Direction::West,
+            Trigram::Gen => Direction::NorthWest,
+            Trigram::Kun => Direction::North,
+        }
+    }
+
+    /// 后天八卦方位：离南坎北，震东兑西，乾西北、坤西南，艮东北、巽东南
+    pub fn hou_tian_direction(&self) -> Direction {
+        match self {
+            Trigram::Qian => Direction::NorthWest,
+            Trigram::Dui => Direction::West,
+            Trigram::Li => Direction::South,
+            Trigram::Zhen => Direction::East,
+            Trigram::Xun => Direction::SouthEast,
+            Trigram::Kan => Direction::North,
+            Trigram::Gen => Direction::NorthEast,
+            Trigram::Kun => Direction::SouthWest,
+        }
+    }
+
+    /// 先天八卦中位于该方位的卦
+    pub fn from_xian_tian_direction(direction: Direction) -> Self {
+        *Self::ALL.iter().find(|trigram| trigram.xian_tian_direction() == direction).unwrap()
+    }
+
+    /// 后天八卦中位于该方位的卦
+    pub fn from_hou_tian_direction(direction: Direction) -> Self {
+        *Self::ALL.iter().find(|trigram| trigram.hou_tian_direction() == direction).unwrap()
+    }
+
+    /// 家人：乾父坤母，震坎艮为长中少男，巽离兑为长中少女
+    pub fn family_role(&self) -> FamilyRole {
+        match self {
+            Trigram::Qian => FamilyRole::Father,
+            Trigram::Kun => FamilyRole::Mother,
+            Trigram::Zhen => FamilyRole::EldestSon,
+            Trigram::Kan => FamilyRole::MiddleSon,
+            Trigram::Gen => FamilyRole::YoungestSon,
+            Trigram::Xun => FamilyRole::EldestDaughter,
+            Trigram::Li => FamilyRole::MiddleDaughter,
+            Trigram::Dui => FamilyRole::YoungestDaughter,
+        }
+    }
+
+    /// 身体（《说卦》）：乾为首，坤为腹，震为足，巽为股，坎为耳，离为目，艮为手，兑为口
+    pub fn body_part(&self) -> &'static str {
+        match self {
+            Trigram::Qian => "首",
+            Trigram::Kun => "腹",
+            Trigram::Zhen => "足",
+            Trigram::Xun => "股",
+            Trigram::Kan => "耳",
+            Trigram::Li => "目",
+            Trigram::Gen => "手",
+            Trigram::Dui => "口",
+        }
+    }
+
+    /// 动物（《说卦》）：乾为马，坤为牛，震为龙，巽为鸡，坎为豕，离为雉，艮为狗，兑为羊
+    pub fn animal(&self) -> &'static str {
+        match self {
+            Trigram::Qian => "马",
+            Trigram::Kun => "牛",
+            Trigram::Zhen => "龙",
+            Trigram::Xun => "鸡",
+            Trigram::Kan => "豕",
+            Trigram::Li => "雉",
+            Trigram::Gen => "狗",
+            Trigram::Dui => "羊",
+        }
+    }
+
+    /// 自然之象：天、泽、火、雷、风、水、山、地
+    pub fn image(&self) -> &'static str {
+        match self {
+            Trigram::Qian => "天",
+            Trigram::Dui => "泽",
+            Trigram::Li => "火",
+            Trigram::Zhen => "雷",
+            Trigram::Xun => "风",
+            Trigram::Kan => "水",
+            Trigram::Gen => "山",
+            Trigram::Kun => "地",
+        }
+    }
+}
+
+impl Trigram {
+    /// 八卦，按乾兑离震巽坎艮坤排列
+    pub const ALL: [Trigram; 8] = [
+        Trigram::Qian, Trigram::Dui, Trigram::Li, Trigram::Zhen,
+        Trigram::Xun, Trigram::Kan, Trigram::Gen, Trigram::Kun,
+    ];
+
+    // 可解析的别名：简繁中文、卦符与拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Trigram::Qian => &["乾", "☰", "qian"],
+            Trigram::Dui => &["兑", "兌", "☱", "dui"],
+            Trigram::Li => &["离", "離", "☲", "li"],
+            Trigram::Zhen => &["震", "☳", "zhen"],
+            Trigram::Xun => &["巽", "☴", "xun"],
+            Trigram::Kan => &["坎", "☵", "kan"],
+            Trigram::Gen => &["艮", "☶", "gen"],
+            Trigram::Kun => &["坤", "☷", "kun"],
+        }
+    }
+}
+
+impl FromStr for Trigram {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "八卦", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(Trigram, "八卦");
+
+impl Serialize for Trigram {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("lines", &self.lines())?;
+            map.serialize_entry("element", &self.wuxing())?;
+            map.serialize_entry("polarity", &self.yinyang())?;
+            map.serialize_entry("image", self.image())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attributes() {
+        for trigram in Trigram::ALL {
+            assert_eq!(Trigram::from_index(trigram.index()), trigram);
+            assert_eq!(Trigram::from_hou_tian_index(trigram.hou_tian_index()), Some(trigram));
+            assert_eq!(Trigram::from_xian_tian_direction(trigram.xian_tian_direction()), trigram);
+            assert_eq!(Trigram::from_hou_tian_direction(trigram.hou_tian_direction()), trigram);
+            // 先天八卦两两相对，三爻阴阳全反
+            let opposite = Trigram::from_xian_tian_direction(trigram.xian_tian_direction().opposite());
+            assert_eq!(opposite.lines(), trigram.lines().map(|line| line.opposite()));
+            // 洛书对宫之数相加为十
+            let opposite = Trigram::from_hou_tian_direction(trigram.hou_tian_direction().opposite());
+            assert_eq!(opposite.hou_tian_index() + trigram.hou_tian_index(), 10);
+        }
+        assert_eq!(Trigram::from_hou_tian_index(5), None);
+        assert_eq!(Trigram::Kan.hou_tian_direction(), Direction::North);
+        assert_eq!(Trigram::Kun.xian_tian_direction(), Direction::North);
+        assert_eq!(Trigram::Xun.wuxing(), WuXing::Wood);
+        assert_eq!(Trigram::Zhen.yinyang(), YinYang::Yang);
+        assert_eq!(Trigram::Li.family_role().chinese_name(), "中女");
+        assert_eq!(Trigram::Dui.image(), "泽");
+    }
+}
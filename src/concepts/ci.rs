@@ -0,0 +1,265 @@
+// src/concepts/ci.rs - 十二次模块
+// 星纪、玄枵……析木，黄道上的范围、对应的地支宫与《晋书·天文志》的州国分野
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+use crate::concepts::gan_zhi::EarthlyBranch;
+use crate::concepts::traits::{ChineseName, Pinyin, Index, Iter};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::json;
+
+/// 十二次，自星纪起按黄经增大的方向排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiStation {
+    /// 星纪（丑）
+    XingJi,
+    /// 玄枵（子）
+    XuanXiao,
+    /// 娵訾（亥）
+    JuZi,
+    /// 降娄（戌）
+    XiangLou,
+    /// 大梁（酉）
+    DaLiang,
+    /// 实沈（申）
+    ShiChen,
+    /// 鹑首（未）
+    ChunShou,
+    /// 鹑火（午）
+    ChunHuo,
+    /// 鹑尾（巳）
+    ChunWei,
+    /// 寿星（辰）
+    ShouXing,
+    /// 大火（卯）
+    DaHuo,
+    /// 析木（寅）
+    XiMu,
+}
+
+impl ChineseName for CiStation {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            CiStation::XingJi => "星纪",
+            CiStation::XuanXiao => "玄枵",
+            CiStation::JuZi => "娵訾",
+            CiStation::XiangLou => "降娄",
+            CiStation::DaLiang => "大梁",
+            CiStation::ShiChen => "实沈",
+            CiStation::ChunShou => "鹑首",
+            CiStation::ChunHuo => "鹑火",
+            CiStation::ChunWei => "鹑尾",
+            CiStation::ShouXing => "寿星",
+            CiStation::DaHuo => "大火",
+            CiStation::XiMu => "析木",
+        }
+    }
+}
+
+impl Pinyin for CiStation {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            CiStation::XingJi => "xīng jì",
+            CiStation::XuanXiao => "xuán xiāo",
+            CiStation::JuZi => "jū zī",
+            CiStation::XiangLou => "xiáng lóu",
+            CiStation::DaLiang => "dà liáng",
+            CiStation::ShiChen => "shí chén",
+            CiStation::ChunShou => "chún shǒu",
+            CiStation::ChunHuo => "chún huǒ",
+            CiStation::ChunWei => "chún wěi",
+            CiStation::ShouXing => "shòu xīng",
+            CiStation::DaHuo => "dà huǒ",
+            CiStation::XiMu => "xī mù",
+        }
+    }
+}
+
+impl Index for CiStation {
+    // 序号按12循环，0视为12（析木）
+    fn from_index(index: usize) -> Self {
+        Self::ALL[(index + 11) % 12]
+    }
+
+    fn index(&self) -> usize {
+        *self as usize + 1
+    }
+}
+
+impl Iter for CiStation {
+    type Item = Self;
+
+    /// 黄经增大方向的下一次，析木之后为星纪
+    fn next(&self) -> Self::Item {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// 黄经减小方向的下一次，星纪之前为析木
+    fn prev(&self) -> Self::Item {
+        Self::from_index(self.index() + 11)
+    }
+}
+
+/// 十二次的起界
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum CiBoundary {
+    /// 以中气为次首：冬至为星纪之首，与黄道十二宫同界
+    #[default]
+    ZhongQi,
+    /// 以节气为次首：冬至居星纪之中，从《汉书·律历志》
+    JieQi,
+}
+
+/// 十二次之一及次内度数
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CiPlacement {
+    /// 所在之次
+    pub station: CiStation,
+    /// 距该次起点的度数（0..30）
+    pub degree: f64,
+}
+
+impl CiStation {
+    /// 十二次，自星纪至析木
+    pub const ALL: [CiStation; 12] = [
+        CiStation::XingJi, CiStation::XuanXiao, CiStation::JuZi, CiStation::XiangLou,
+        CiStation::DaLiang, CiStation::ShiChen, CiStation::ChunShou, CiStation::ChunHuo,
+        CiStation::ChunWei, CiStation::ShouXing, CiStation::DaHuo, CiStation::XiMu,
+    ];
+
+    /// 对应的地支宫：星纪丑、玄枵子、娵訾亥……析木寅
+    pub fn branch(&self) -> EarthlyBranch {
+        EarthlyBranch::from_index(3 + 12 - self.index())
+    }
+
+    /// 由地支宫求次
+    pub fn from_branch(branch: EarthlyBranch) -> Self {
+        Self::from_index(3 + 12 - branch.index())
+    }
+
+    /// 次首的黄经（度）
+    pub fn start(&self, boundary: CiBoundary) -> f64 {
+        let start = 270.0 + 30.0 * (self.index() - 1) as f64 - match boundary {
+            CiBoundary::ZhongQi => 0.0,
+            CiBoundary::JieQi => 15.0,
+        };
+        start % 360.0
+    }
+
+    /// 黄道上的范围（起, 止），止点可能跨过0°
+    pub fn range(&self, boundary: CiBoundary) -> (f64, f64) {
+        let start = self.start(boundary);
+        (start, (start + 30.0) % 360.0)
+    }
+
+    /// 黄经所在之次
+    pub fn from_longitude(longitude: f64, boundary: CiBoundary) -> CiPlacement {
+        let from_xing_ji = ((longitude - Self::XingJi.start(boundary)) % 360.0 + 360.0) % 360.0;
+        let slot = ((from_xing_ji / 30.0) as usize).min(11);
+        CiPlacement {
+            station: Self::ALL[slot],
+            degree: from_xing_ji - slot as f64 * 30.0,
+        }
+    }
+
+    /// 分野之国，从《晋书·天文志》
+    pub fn state(&self) -> &'static str {
+        match self {
+            CiStation::XingJi => "吴越",
+            CiStation::XuanXiao => "齐",
+            CiStation::JuZi => "卫",
+            CiStation::XiangLou => "鲁",
+            CiStation::DaLiang => "赵",
+            CiStation::ShiChen => "魏",
+            CiStation::ChunShou => "秦",
+            CiStation::ChunHuo => "周",
+            CiStation::ChunWei => "楚",
+            CiStation::ShouXing => "郑",
+            CiStation::DaHuo => "宋",
+            CiStation::XiMu => "燕",
+        }
+    }
+
+    /// 分野之州，从《晋书·天文志》
+    pub fn province(&self) -> &'static str {
+        match self {
+            CiStation::XingJi => "扬州",
+            CiStation::XuanXiao => "青州",
+            CiStation::JuZi => "并州",
+            CiStation::XiangLou => "徐州",
+            CiStation::DaLiang => "冀州",
+            CiStation::ShiChen => "益州",
+            CiStation::ChunShou => "雍州",
+            CiStation::ChunHuo => "三河",
+            CiStation::ChunWei => "荆州",
+            CiStation::ShouXing => "兖州",
+            CiStation::DaHuo => "豫州",
+            CiStation::XiMu => "幽州",
+        }
+    }
+
+    // 可解析的别名：简繁中文与拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            CiStation::XingJi => &["星纪", "星紀", "xingji"],
+            CiStation::XuanXiao => &["玄枵", "xuanxiao"],
+            CiStation::JuZi => &["娵訾", "娵觜", "诹訾", "juzi"],
+            CiStation::XiangLou => &["降娄", "降婁", "xianglou", "jianglou"],
+            CiStation::DaLiang => &["大梁", "daliang"],
+            CiStation::ShiChen => &["实沈", "實沈", "shichen", "shishen"],
+            CiStation::ChunShou => &["鹑首", "鶉首", "chunshou"],
+            CiStation::ChunHuo => &["鹑火", "鶉火", "chunhuo"],
+            CiStation::ChunWei => &["鹑尾", "鶉尾", "chunwei"],
+            CiStation::ShouXing => &["寿星", "壽星", "shouxing"],
+            CiStation::DaHuo => &["大火", "dahuo"],
+            CiStation::XiMu => &["析木", "ximu"],
+        }
+    }
+}
+
+impl FromStr for CiStation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "十二次", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(CiStation, "十二次");
+
+impl Serialize for CiStation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("branch", &self.branch())?;
+            map.serialize_entry("state", self.state())?;
+            map.serialize_entry("province", self.province())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stations() {
+        assert_eq!(CiStation::XingJi.branch(), EarthlyBranch::Chou);
+        assert_eq!(CiStation::XuanXiao.branch(), EarthlyBranch::Zi);
+        assert_eq!(CiStation::XiMu.branch(), EarthlyBranch::Yin);
+        for station in CiStation::ALL {
+            assert_eq!(CiStation::from_branch(station.branch()), station);
+        }
+        assert_eq!(CiStation::DaHuo.state(), "宋");
+        assert_eq!("降婁".parse::<CiStation>().unwrap(), CiStation::XiangLou);
+
+        // 春分点在降娄之首；按节气起次则在降娄之中
+        assert_eq!(CiStation::XiangLou.range(CiBoundary::ZhongQi), (0.0, 30.0));
+        assert_eq!(CiStation::JuZi.range(CiBoundary::JieQi), (315.0, 345.0));
+        let placement = CiStation::from_longitude(0.0, CiBoundary::ZhongQi);
+        assert_eq!((placement.station, placement.degree), (CiStation::XiangLou, 0.0));
+        let placement = CiStation::from_longitude(0.0, CiBoundary::JieQi);
+        assert_eq!((placement.station, placement.degree), (CiStation::XiangLou, 15.0));
+        let placement = CiStation::from_longitude(-10.0, CiBoundary::ZhongQi);
+        assert_eq!((placement.station, placement.degree), (CiStation::JuZi, 20.0));
+    }
+}
@@ -0,0 +1,133 @@
+// src/concepts/direction.rs - 八方模块
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+use crate::concepts::traits::{ChineseName, Pinyin, Index, Iter};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::json;
+
+/// 八方枚举，从北开始按顺时针排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// 北
+    North,
+    /// 东北
+    NorthEast,
+    /// 东
+    East,
+    /// 东南
+    SouthEast,
+    /// 南
+    South,
+    /// 西南
+    SouthWest,
+    /// 西
+    West,
+    /// 西北
+    NorthWest,
+}
+
+impl ChineseName for Direction {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Direction::North => "北",
+            Direction::NorthEast => "东北",
+            Direction::East => "东",
+            Direction::SouthEast => "东南",
+            Direction::South => "南",
+            Direction::SouthWest => "西南",
+            Direction::West => "西",
+            Direction::NorthWest => "西北",
+        }
+    }
+}
+
+impl Pinyin for Direction {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            Direction::North => "běi",
+            Direction::NorthEast => "dōng běi",
+            Direction::East => "dōng",
+            Direction::SouthEast => "dōng nán",
+            Direction::South => "nán",
+            Direction::SouthWest => "xī nán",
+            Direction::West => "xī",
+            Direction::NorthWest => "xī běi",
+        }
+    }
+}
+
+impl Index for Direction {
+    // 序号按8循环，0视为8（西北）
+    fn from_index(index: usize) -> Self {
+        Self::ALL[(index + 7) % 8]
+    }
+
+    fn index(&self) -> usize {
+        *self as usize + 1
+    }
+}
+
+impl Iter for Direction {
+    type Item = Self;
+
+    /// 顺时针的下一方
+    fn next(&self) -> Self::Item {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// 逆时针的下一方
+    fn prev(&self) -> Self::Item {
+        Self::from_index(self.index() + 7)
+    }
+}
+
+impl Direction {
+    /// 八方，从北开始按顺时针排列
+    pub const ALL: [Direction; 8] = [
+        Direction::North, Direction::NorthEast, Direction::East, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::West, Direction::NorthWest,
+    ];
+
+    /// 方位中线的方位角（度），正北为0，顺时针增加
+    pub fn azimuth(&self) -> f64 {
+        (self.index() - 1) as f64 * 45.0
+    }
+
+    /// 对宫方向
+    pub fn opposite(&self) -> Self {
+        Self::from_index(self.index() + 4)
+    }
+
+    // 可解析的别名：简繁中文、拼音与英文
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Direction::North => &["北", "bei", "north", "n"],
+            Direction::NorthEast => &["东北", "東北", "dongbei", "northeast", "ne"],
+            Direction::East => &["东", "東", "dong", "east", "e"],
+            Direction::SouthEast => &["东南", "東南", "dongnan", "southeast", "se"],
+            Direction::South => &["南", "nan", "south", "s"],
+            Direction::SouthWest => &["西南", "xinan", "southwest", "sw"],
+            Direction::West => &["西", "xi", "west", "w"],
+            Direction::NorthWest => &["西北", "xibei", "northwest", "nw"],
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "方位", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(Direction, "方位");
+
+impl Serialize for Direction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("azimuth", &self.azimuth())
+        })
+    }
+}
@@ -1,10 +1,20 @@
-use serde::Serialize;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::str::FromStr;
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde::{Serialize, Serializer};
+use serde::ser::{SerializeMap, SerializeStruct};
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
 use crate::concepts::yinyang::YinYang;
 use crate::concepts::wu_xing::WuXing;
-use crate::concepts::traits::{YinYangTrait, WuXingTrait, ChineseName, Index, Iter};
+use crate::concepts::traits::{YinYangTrait, WuXingTrait, ChineseName, Pinyin, Index, Iter};
+use crate::concepts::parse::{self, ParseError, FromStrVisitor, impl_deserialize_from_str};
+use crate::json::{self, ConceptStyle};
 
 /// 天干枚举
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HeavenlyStem {
     /// 甲
     Jia,
@@ -68,9 +78,27 @@ impl ChineseName for HeavenlyStem {
     }
 }
 
+impl Pinyin for HeavenlyStem {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            HeavenlyStem::Jia => "jiǎ",
+            HeavenlyStem::Yi => "yǐ",
+            HeavenlyStem::Bing => "bǐng",
+            HeavenlyStem::Ding => "dīng",
+            HeavenlyStem::Wu => "wù",
+            HeavenlyStem::Ji => "jǐ",
+            HeavenlyStem::Geng => "gēng",
+            HeavenlyStem::Xin => "xīn",
+            HeavenlyStem::Ren => "rén",
+            HeavenlyStem::Gui => "guǐ",
+        }
+    }
+}
+
 impl Index for HeavenlyStem {
     fn from_index(index: usize) -> Self {
-        match (index - 1) % 10 {
+        // 序号按10循环，0视为10（癸）
+        match (index + 9) % 10 {
             0 => HeavenlyStem::Jia,
             1 => HeavenlyStem::Yi,
             2 => HeavenlyStem::Bing,
@@ -113,8 +141,52 @@ impl Iter for HeavenlyStem {
     }
 }
 
+impl HeavenlyStem {
+    /// 十天干，按甲乙丙丁排列
+    pub const ALL: [HeavenlyStem; 10] = [
+        HeavenlyStem::Jia, HeavenlyStem::Yi, HeavenlyStem::Bing, HeavenlyStem::Ding,
+        HeavenlyStem::Wu, HeavenlyStem::Ji, HeavenlyStem::Geng, HeavenlyStem::Xin,
+        HeavenlyStem::Ren, HeavenlyStem::Gui,
+    ];
+
+    // 可解析的别名：中文与无声调拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            HeavenlyStem::Jia => &["甲", "jia"],
+            HeavenlyStem::Yi => &["乙", "yi"],
+            HeavenlyStem::Bing => &["丙", "bing"],
+            HeavenlyStem::Ding => &["丁", "ding"],
+            HeavenlyStem::Wu => &["戊", "wu"],
+            HeavenlyStem::Ji => &["己", "ji"],
+            HeavenlyStem::Geng => &["庚", "geng"],
+            HeavenlyStem::Xin => &["辛", "xin"],
+            HeavenlyStem::Ren => &["壬", "ren"],
+            HeavenlyStem::Gui => &["癸", "gui"],
+        }
+    }
+}
+
+impl FromStr for HeavenlyStem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "天干", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(HeavenlyStem, "天干");
+
+impl Serialize for HeavenlyStem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("element", &self.wuxing())?;
+            map.serialize_entry("polarity", &self.yinyang())
+        })
+    }
+}
+
 /// 地支枚举
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EarthlyBranch {
     /// 子
     Zi,
@@ -146,9 +218,9 @@ impl YinYangTrait for EarthlyBranch {
     fn yinyang(&self) -> YinYang {
         match self {
             EarthlyBranch::Zi | EarthlyBranch::Wu | EarthlyBranch::Chen | 
-            EarthlyBranch::You | EarthlyBranch::Shen | EarthlyBranch::Yin => YinYang::Yang,
+            EarthlyBranch::Xu | EarthlyBranch::Shen | EarthlyBranch::Yin => YinYang::Yang,
             EarthlyBranch::Chou | EarthlyBranch::Wei | EarthlyBranch::Si | 
-            EarthlyBranch::Hai | EarthlyBranch::Mao | EarthlyBranch::Xu => YinYang::Yin,
+            EarthlyBranch::Hai | EarthlyBranch::Mao | EarthlyBranch::You => YinYang::Yin,
         }
     }
 }
@@ -185,9 +257,29 @@ impl ChineseName for EarthlyBranch {
     }
 }
 
+impl Pinyin for EarthlyBranch {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            EarthlyBranch::Zi => "zǐ",
+            EarthlyBranch::Chou => "chǒu",
+            EarthlyBranch::Yin => "yín",
+            EarthlyBranch::Mao => "mǎo",
+            EarthlyBranch::Chen => "chén",
+            EarthlyBranch::Si => "sì",
+            EarthlyBranch::Wu => "wǔ",
+            EarthlyBranch::Wei => "wèi",
+            EarthlyBranch::Shen => "shēn",
+            EarthlyBranch::You => "yǒu",
+            EarthlyBranch::Xu => "xū",
+            EarthlyBranch::Hai => "hài",
+        }
+    }
+}
+
 impl Index for EarthlyBranch {
     fn from_index(index: usize) -> Self {
-        match (index - 1) % 12 {
+        // 序号按12循环，0视为12（亥）
+        match (index + 11) % 12 {
             0 => EarthlyBranch::Zi,
             1 => EarthlyBranch::Chou,
             2 => EarthlyBranch::Yin,
@@ -231,8 +323,59 @@ impl Iter for EarthlyBranch {
     }
 }
 
+impl EarthlyBranch {
+    /// 十二地支，按子丑寅卯排列
+    pub const ALL: [EarthlyBranch; 12] = [
+        EarthlyBranch::Zi, EarthlyBranch::Chou, EarthlyBranch::Yin, EarthlyBranch::Mao,
+        EarthlyBranch::Chen, EarthlyBranch::Si, EarthlyBranch::Wu, EarthlyBranch::Wei,
+        EarthlyBranch::Shen, EarthlyBranch::You, EarthlyBranch::Xu, EarthlyBranch::Hai,
+    ];
+
+    /// 六冲：相隔六位的地支，如子午相冲
+    pub fn chong(&self) -> EarthlyBranch {
+        Self::from_index(self.index() + 6)
+    }
+
+    // 可解析的别名：中文与无声调拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            EarthlyBranch::Zi => &["子", "zi"],
+            EarthlyBranch::Chou => &["丑", "chou"],
+            EarthlyBranch::Yin => &["寅", "yin"],
+            EarthlyBranch::Mao => &["卯", "mao"],
+            EarthlyBranch::Chen => &["辰", "chen"],
+            EarthlyBranch::Si => &["巳", "si"],
+            EarthlyBranch::Wu => &["午", "wu"],
+            EarthlyBranch::Wei => &["未", "wei"],
+            EarthlyBranch::Shen => &["申", "shen"],
+            EarthlyBranch::You => &["酉", "you"],
+            EarthlyBranch::Xu => &["戌", "xu"],
+            EarthlyBranch::Hai => &["亥", "hai"],
+        }
+    }
+}
+
+impl FromStr for EarthlyBranch {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "地支", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(EarthlyBranch, "地支");
+
+impl Serialize for EarthlyBranch {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("element", &self.wuxing())?;
+            map.serialize_entry("polarity", &self.yinyang())
+        })
+    }
+}
+
 /// 六十甲子结构体
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GanZhi {
     /// 天干
     pub stem: HeavenlyStem,
@@ -272,6 +415,20 @@ const SEXAGESIMAL_CYCLE: [&str; 60] = [
     "甲寅", "乙卯", "丙辰", "丁巳", "戊午", "己未", "庚申", "辛酉", "壬戌", "癸亥",
 ];
 
+// 六十甲子查找表，第i项的天干为第i%10个、地支为第i%12个
+const CYCLE: [GanZhi; 60] = {
+    let mut table = [GanZhi { stem: HeavenlyStem::Jia, branch: EarthlyBranch::Zi }; 60];
+    let mut i = 0;
+    while i < 60 {
+        table[i] = GanZhi {
+            stem: HeavenlyStem::ALL[i % 10],
+            branch: EarthlyBranch::ALL[i % 12],
+        };
+        i += 1;
+    }
+    table
+};
+
 impl Index for GanZhi {
     // 根据天干index和地支index，计算出六十甲子的index
     // 计算模型：
@@ -284,18 +441,9 @@ impl Index for GanZhi {
         let x = if h < e { 12 + h - e } else { h - e };
         h + x * 5
     }
-    // 根据六十甲子的index，计算出天干index和地支index
-    // 计算模型：
-    // 定义天干的index为h，地支的index为e，六十甲子的index为s
-    // h = s % 10，
-    // e = s % 12
+    // 根据六十甲子的index查表，序号按60循环，0视为60（癸亥）
     fn from_index(index: usize) -> Self {
-        let h = index % 10;
-        let e = index % 12;
-        GanZhi::new(
-            HeavenlyStem::from_index(h), 
-            EarthlyBranch::from_index(e)
-        ).unwrap()
+        CYCLE[(index + 59) % 60]
     }
 }
 
@@ -303,11 +451,51 @@ impl Iter for GanZhi {
     type Item = Self;
 
     fn next(&self) -> Self::Item {
-        Self::from_index(self.index() + 1)
+        *self + 1
     }
 
     fn prev(&self) -> Self::Item {
-        Self::from_index(self.index() + 59)
+        *self - 1
+    }
+}
+
+impl Add<i64> for GanZhi {
+    type Output = GanZhi;
+
+    /// 向后推n位，n可为负数，按60循环
+    fn add(self, n: i64) -> GanZhi {
+        let position = (self.index() as i64 - 1 + n % 60).rem_euclid(60);
+        CYCLE[position as usize]
+    }
+}
+
+impl Sub<i64> for GanZhi {
+    type Output = GanZhi;
+
+    /// 向前推n位，n可为负数，按60循环
+    fn sub(self, n: i64) -> GanZhi {
+        self + -(n % 60)
+    }
+}
+
+impl AddAssign<i64> for GanZhi {
+    fn add_assign(&mut self, n: i64) {
+        *self = *self + n;
+    }
+}
+
+impl SubAssign<i64> for GanZhi {
+    fn sub_assign(&mut self, n: i64) {
+        *self = *self - n;
+    }
+}
+
+impl Sub for GanZhi {
+    type Output = i64;
+
+    /// 从other向后推到self所需的步数，范围0..60，满足 other + (self - other) == self
+    fn sub(self, other: GanZhi) -> i64 {
+        (self.index() as i64 - other.index() as i64).rem_euclid(60)
     }
 }
 
@@ -320,6 +508,35 @@ impl ChineseName for GanZhi {
 }
 
 impl GanZhi {
+    /// 六十甲子，从甲子到癸亥依次排列
+    pub const ALL: [GanZhi; 60] = CYCLE;
+
+    /// 从甲子开始依次迭代六十甲子
+    pub fn all() -> GanZhiRange {
+        GanZhiRange { start: CYCLE[0], len: 60 }
+    }
+
+    /// 从start开始迭代到end（不含），跨越癸亥时循环回到甲子；start与end相同时为空
+    pub fn range(start: GanZhi, end: GanZhi) -> GanZhiRange {
+        GanZhiRange { start, len: (end - start) as usize }
+    }
+
+    /// 从start开始迭代到end（含），跨越癸亥时循环回到甲子；start与end相同时只含一项
+    pub fn range_inclusive(start: GanZhi, end: GanZhi) -> GanZhiRange {
+        GanZhiRange { start, len: (end - start) as usize + 1 }
+    }
+
+    /// 从self开始依次迭代len项，可超过60项（循环）
+    pub fn iter_from(self, len: usize) -> GanZhiRange {
+        GanZhiRange { start: self, len }
+    }
+
+    /// 到other的带符号最短距离，范围-29..=30，正数表示other在后
+    pub fn distance_to(&self, other: &GanZhi) -> i64 {
+        let forward = *other - *self;
+        if forward > 30 { forward - 60 } else { forward }
+    }
+
     /// 创建一个新的六十甲子实例
     pub fn new(stem: HeavenlyStem, branch: EarthlyBranch) -> Result<Self, &'static str> {
         if stem.yinyang() != branch.yinyang() {
@@ -339,18 +556,190 @@ impl GanZhi {
     }
 
     /// 获取纳音名称
-    pub fn sound(&self) -> (&str, WuXing) {
+    pub fn sound(&self) -> (&'static str, WuXing) {
         let index = self.index();
         // sound_index是index向上整除2
         let sound_index = (index + 1) / 2;
         // 因为SOUNDS数组早就规定好而且是常量，所以没有必要进行长度检查
         SOUNDS[sound_index - 1]
     }
+
+    /// 获取所在的旬，以旬首表示（甲子、甲戌、甲申、甲午、甲辰、甲寅）
+    pub fn xun(&self) -> GanZhi {
+        // 旬首天干为甲，地支向前退回与天干相同的步数
+        let offset = self.stem.index() - 1;
+        GanZhi {
+            stem: HeavenlyStem::Jia,
+            branch: EarthlyBranch::from_index(self.branch.index() + 12 - offset),
+        }
+    }
+
+    /// 获取旬空（空亡）的两个地支，即旬首之前的两支，如甲子旬空戌亥
+    pub fn kong_wang(&self) -> [EarthlyBranch; 2] {
+        let head = self.xun().branch;
+        [head.prev().prev(), head.prev()]
+    }
+
+    /// 判断地支是否落入本旬空亡
+    pub fn is_kong_wang(&self, branch: &EarthlyBranch) -> bool {
+        self.kong_wang().contains(branch)
+    }
+}
+
+/// 六十甲子的循环区间迭代器
+#[derive(Debug, Clone)]
+pub struct GanZhiRange {
+    start: GanZhi,
+    len: usize,
+}
+
+impl Iterator for GanZhiRange {
+    type Item = GanZhi;
+
+    fn next(&mut self) -> Option<GanZhi> {
+        if self.len == 0 {
+            return None;
+        }
+        let current = self.start;
+        self.start += 1;
+        self.len -= 1;
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl DoubleEndedIterator for GanZhiRange {
+    fn next_back(&mut self) -> Option<GanZhi> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.start + (self.len % 60) as i64)
+    }
+}
+
+impl ExactSizeIterator for GanZhiRange {}
+
+impl FusedIterator for GanZhiRange {}
+
+impl FromStr for GanZhi {
+    type Err = ParseError;
+
+    /// 解析六十甲子，接受"庚午"、"gengwu"、"Gēng Wǔ"或序号"7"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = parse::normalize(s);
+        if let Ok(index) = normalized.parse::<usize>() {
+            if !(1..=60).contains(&index) {
+                return Err(ParseError::IndexOutOfRange { kind: "六十甲子", index, max: 60 });
+            }
+            return Ok(GanZhi::from_index(index));
+        }
+        // 依次尝试以每个天干的别名作为前缀，剩余部分作为地支
+        let split = HeavenlyStem::ALL.iter().find_map(|stem| {
+            stem.aliases().iter().find_map(|alias| {
+                let rest = normalized.strip_prefix(alias)?;
+                let branch = EarthlyBranch::ALL.iter().find(|b| b.aliases().contains(&rest))?;
+                Some((*stem, *branch))
+            })
+        });
+        match split {
+            Some((stem, branch)) => GanZhi::new(stem, branch)
+                .map_err(|_| ParseError::YinYangMismatch { stem, branch }),
+            None => Err(ParseError::UnknownName { kind: "六十甲子", input: s.to_string() }),
+        }
+    }
+}
+
+// Rich 表示中的纳音
+#[derive(Serialize)]
+struct NaYin {
+    name: &'static str,
+    element: WuXing,
+}
+
+impl Serialize for GanZhi {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match json::concept_style() {
+            ConceptStyle::Name => {
+                let mut state = serializer.serialize_struct("GanZhi", 2)?;
+                state.serialize_field("stem", &self.stem)?;
+                state.serialize_field("branch", &self.branch)?;
+                state.end()
+            }
+            ConceptStyle::Chinese => serializer.serialize_str(self.chinese_name()),
+            ConceptStyle::Pinyin => {
+                serializer.serialize_str(&format!("{} {}", self.stem.pinyin(), self.branch.pinyin()))
+            }
+            ConceptStyle::Index => serializer.serialize_u64(self.index() as u64),
+            ConceptStyle::Rich => {
+                let (name, element) = self.sound();
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_entry("chinese", self.chinese_name())?;
+                map.serialize_entry("pinyin", &format!("{} {}", self.stem.pinyin(), self.branch.pinyin()))?;
+                map.serialize_entry("index", &self.index())?;
+                map.serialize_entry("stem", &self.stem)?;
+                map.serialize_entry("branch", &self.branch)?;
+                map.serialize_entry("nayin", &NaYin { name, element })?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GanZhi {
+    /// 接受字符串、序号，或含 stem 与 branch 字段的对象
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct GanZhiVisitor;
+
+        impl<'de> Visitor<'de> for GanZhiVisitor {
+            type Value = GanZhi;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("六十甲子的名称、拼音、序号或 {stem, branch} 对象")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<GanZhi, E> {
+                FromStrVisitor::<GanZhi>::new("六十甲子").visit_str(v)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<GanZhi, E> {
+                self.visit_str(&v.to_string())
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<GanZhi, E> {
+                self.visit_str(&v.to_string())
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<GanZhi, A::Error> {
+                let mut stem: Option<HeavenlyStem> = None;
+                let mut branch: Option<EarthlyBranch> = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "stem" => stem = Some(map.next_value()?),
+                        "branch" => branch = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                let stem = stem.ok_or_else(|| de::Error::missing_field("stem"))?;
+                let branch = branch.ok_or_else(|| de::Error::missing_field("branch"))?;
+                GanZhi::new(stem, branch)
+                    .map_err(|_| de::Error::custom(ParseError::YinYangMismatch { stem, branch }))
+            }
+        }
+
+        deserializer.deserialize_any(GanZhiVisitor)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_gan_zhi_serialization() {
@@ -360,4 +749,142 @@ mod tests {
         assert!(json.contains("stem"));
         assert!(json.contains("branch"));
     }
+
+    #[test]
+    fn test_branch_yinyang() {
+        // 地支自子起阳阴相间：戌为阳、酉为阴
+        for branch in EarthlyBranch::ALL {
+            let expected = if branch.index() % 2 == 1 { YinYang::Yang } else { YinYang::Yin };
+            assert_eq!(branch.yinyang(), expected, "{}", branch.chinese_name());
+        }
+        assert_eq!(EarthlyBranch::Xu.yinyang(), YinYang::Yang);
+        assert_eq!(EarthlyBranch::You.yinyang(), YinYang::Yin);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("甲".parse::<HeavenlyStem>(), Ok(HeavenlyStem::Jia));
+        assert_eq!("Gēng".parse::<HeavenlyStem>(), Ok(HeavenlyStem::Geng));
+        assert_eq!("10".parse::<HeavenlyStem>(), Ok(HeavenlyStem::Gui));
+        assert_eq!("hai".parse::<EarthlyBranch>(), Ok(EarthlyBranch::Hai));
+        assert_eq!("wu4".parse::<EarthlyBranch>(), Ok(EarthlyBranch::Wu));
+        assert!("13".parse::<EarthlyBranch>().is_err());
+
+        let geng_wu = GanZhi::new(HeavenlyStem::Geng, EarthlyBranch::Wu).unwrap();
+        assert_eq!("庚午".parse::<GanZhi>(), Ok(geng_wu));
+        assert_eq!("gengwu".parse::<GanZhi>(), Ok(geng_wu));
+        assert_eq!("Gēng Wǔ".parse::<GanZhi>(), Ok(geng_wu));
+        assert_eq!("7".parse::<GanZhi>(), Ok(geng_wu));
+        assert_eq!("60".parse::<GanZhi>().unwrap().chinese_name(), "癸亥");
+        assert_eq!(
+            "甲丑".parse::<GanZhi>(),
+            Err(ParseError::YinYangMismatch { stem: HeavenlyStem::Jia, branch: EarthlyBranch::Chou })
+        );
+        assert!("甲".parse::<GanZhi>().is_err());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let geng_wu = GanZhi::new(HeavenlyStem::Geng, EarthlyBranch::Wu).unwrap();
+        for json in [r#""庚午""#, r#""geng wu""#, "7", r#"{"stem":"Geng","branch":"Wu"}"#] {
+            assert_eq!(serde_json::from_str::<GanZhi>(json).unwrap(), geng_wu);
+        }
+        assert!(serde_json::from_str::<GanZhi>(r#"{"stem":"Jia","branch":"Chou"}"#).is_err());
+
+        for stem in HeavenlyStem::ALL {
+            let json = serde_json::to_string(&stem).unwrap();
+            assert_eq!(serde_json::from_str::<HeavenlyStem>(&json).unwrap(), stem);
+        }
+        for branch in EarthlyBranch::ALL {
+            let json = serde_json::to_string(&branch).unwrap();
+            assert_eq!(serde_json::from_str::<EarthlyBranch>(&json).unwrap(), branch);
+        }
+        for stem in HeavenlyStem::ALL {
+            for branch in EarthlyBranch::ALL {
+                if let Ok(gz) = GanZhi::new(stem, branch) {
+                    let json = serde_json::to_string(&gz).unwrap();
+                    assert_eq!(serde_json::from_str::<GanZhi>(&json).unwrap(), gz);
+                    assert_eq!(gz.chinese_name().parse::<GanZhi>(), Ok(gz));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cycle_table() {
+        assert_eq!(GanZhi::all().len(), 60);
+        for (i, gz) in GanZhi::all().enumerate() {
+            assert_eq!(gz.index(), i + 1);
+            assert_eq!(GanZhi::from_index(i + 1), gz);
+            assert_eq!(GanZhi::from_index(i + 61), gz);
+            assert_eq!(GanZhi::new(gz.stem, gz.branch), Ok(gz));
+            assert_eq!(gz.chinese_name(), SEXAGESIMAL_CYCLE[i]);
+            assert_eq!(gz.next().prev(), gz);
+            assert_eq!(gz.prev().next(), gz);
+        }
+        assert_eq!(GanZhi::from_index(0).chinese_name(), "癸亥");
+        assert_eq!(HeavenlyStem::from_index(0), HeavenlyStem::Gui);
+        assert_eq!(EarthlyBranch::from_index(0), EarthlyBranch::Hai);
+        assert_eq!(GanZhi::ALL[59].next(), GanZhi::ALL[0]);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        for a in GanZhi::ALL {
+            for b in GanZhi::ALL {
+                let steps = b - a;
+                assert!((0..60).contains(&steps));
+                assert_eq!(a + steps, b);
+                assert_eq!(b - steps, a);
+
+                let distance = a.distance_to(&b);
+                assert!((-29..=30).contains(&distance));
+                assert_eq!(a + distance, b);
+                assert_eq!(b.distance_to(&a), if distance == 30 { 30 } else { -distance });
+            }
+            assert_eq!(a + 60, a);
+            assert_eq!(a - 61, a.prev());
+            assert_eq!(a + i64::MAX, a + i64::MAX % 60);
+            assert_eq!(a - i64::MIN, a - i64::MIN % 60);
+        }
+
+        let mut gz: GanZhi = "甲子".parse().unwrap();
+        gz += 6;
+        assert_eq!(gz.chinese_name(), "庚午");
+        gz -= 7;
+        assert_eq!(gz.chinese_name(), "癸亥");
+    }
+
+    #[test]
+    fn test_range() {
+        let gui_hai: GanZhi = "癸亥".parse().unwrap();
+        let bing_yin: GanZhi = "丙寅".parse().unwrap();
+        let names: Vec<_> = GanZhi::range(gui_hai, bing_yin).map(|gz| gz.chinese_name()).collect();
+        assert_eq!(names, ["癸亥", "甲子", "乙丑"]);
+        let names: Vec<_> = GanZhi::range_inclusive(gui_hai, bing_yin).rev().map(|gz| gz.chinese_name()).collect();
+        assert_eq!(names, ["丙寅", "乙丑", "甲子", "癸亥"]);
+
+        assert_eq!(GanZhi::range(gui_hai, gui_hai).count(), 0);
+        assert_eq!(GanZhi::range_inclusive(gui_hai, gui_hai).count(), 1);
+        assert_eq!(GanZhi::range_inclusive(bing_yin, bing_yin.prev()).len(), 60);
+        assert!(GanZhi::all().eq(GanZhi::ALL));
+        assert!(GanZhi::all().rev().eq(GanZhi::ALL.into_iter().rev()));
+        assert_eq!(bing_yin.iter_from(121).next_back(), Some(bing_yin));
+    }
+
+    #[test]
+    fn test_xun_kong_wang() {
+        let gz = GanZhi::new(HeavenlyStem::Geng, EarthlyBranch::Wu).unwrap();
+        assert_eq!(gz.xun().chinese_name(), "甲子");
+        assert_eq!(gz.kong_wang(), [EarthlyBranch::Xu, EarthlyBranch::Hai]);
+
+        let gz = GanZhi::new(HeavenlyStem::Gui, EarthlyBranch::Hai).unwrap();
+        assert_eq!(gz.xun().chinese_name(), "甲寅");
+        assert_eq!(gz.kong_wang(), [EarthlyBranch::Zi, EarthlyBranch::Chou]);
+
+        let gz = GanZhi::new(HeavenlyStem::Yi, EarthlyBranch::You).unwrap();
+        assert_eq!(gz.xun().chinese_name(), "甲申");
+        assert!(gz.is_kong_wang(&EarthlyBranch::Wu));
+        assert!(!gz.is_kong_wang(&EarthlyBranch::Shen));
+    }
 }
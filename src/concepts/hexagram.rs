@@ -0,0 +1,379 @@
+// src/concepts/hexagram.rs - 六十四卦模块
+// 每一卦由上下两个经卦（八卦）组成，爻从下到上编号为1（初爻）到6（上爻）
+use core::fmt;
+use core::str::FromStr;
+use alloc::string::{String, ToString};
+use serde::{Serialize, Serializer};
+use serde::ser::{SerializeMap, SerializeStruct};
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
+use crate::concepts::bagua::Trigram;
+use crate::concepts::yinyang::YinYang;
+use crate::concepts::traits::{ChineseName, Pinyin, Index};
+use crate::concepts::parse::{self, ParseError, FromStrVisitor};
+use crate::json::{self, ConceptStyle};
+
+/// 六十四卦结构体
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hexagram {
+    /// 上卦（外卦）
+    pub upper: Trigram,
+    /// 下卦（内卦）
+    pub lower: Trigram,
+}
+
+// 文王卦序，行为上卦、列为下卦，均按乾兑离震巽坎艮坤排列
+const KING_WEN: [[u8; 8]; 8] = [
+    [1, 10, 13, 25, 44, 6, 33, 12],
+    [43, 58, 49, 17, 28, 47, 31, 45],
+    [14, 38, 30, 21, 50, 64, 56, 35],
+    [34, 54, 55, 51, 32, 40, 62, 16],
+    [9, 61, 37, 42, 57, 59, 53, 20],
+    [5, 60, 63, 3, 48, 29, 39, 8],
+    [26, 41, 22, 27, 18, 4, 52, 23],
+    [11, 19, 36, 24, 46, 7, 15, 2],
+];
+
+// 按文王卦序排列的六十四卦，由 KING_WEN 反查得到
+const SEQUENCE: [Hexagram; 64] = {
+    let mut table = [Hexagram { upper: Trigram::Qian, lower: Trigram::Qian }; 64];
+    let mut upper = 0;
+    while upper < 8 {
+        let mut lower = 0;
+        while lower < 8 {
+            table[KING_WEN[upper][lower] as usize - 1] = Hexagram {
+                upper: Trigram::ALL[upper],
+                lower: Trigram::ALL[lower],
+            };
+            lower += 1;
+        }
+        upper += 1;
+    }
+    table
+};
+
+// 卦名，按文王卦序排列
+const NAMES: [&str; 64] = [
+    "乾", "坤", "屯", "蒙", "需", "讼", "师", "比",
+    "小畜", "履", "泰", "否", "同人", "大有", "谦", "豫",
+    "随", "蛊", "临", "观", "噬嗑", "贲", "剥", "复",
+    "无妄", "大畜", "颐", "大过", "坎", "离", "咸", "恒",
+    "遯", "大壮", "晋", "明夷", "家人", "睽", "蹇", "解",
+    "损", "益", "夬", "姤", "萃", "升", "困", "井",
+    "革", "鼎", "震", "艮", "渐", "归妹", "丰", "旅",
+    "巽", "兑", "涣", "节", "中孚", "小过", "既济", "未济",
+];
+
+// 卦名的繁体或异体写法，与简体相同者不再列出
+const VARIANTS: [(&str, u8); 29] = [
+    ("訟", 6), ("師", 7), ("隨", 17), ("蠱", 18), ("臨", 19), ("觀", 20),
+    ("賁", 22), ("剝", 23), ("復", 24), ("無妄", 25), ("頤", 27), ("大過", 28),
+    ("習坎", 29), ("习坎", 29), ("離", 30), ("恆", 32), ("遁", 33), ("大壯", 34),
+    ("晉", 35), ("損", 41), ("漸", 53), ("歸妹", 54), ("豐", 55), ("兌", 58),
+    ("渙", 59), ("節", 60), ("小過", 62), ("既濟", 63), ("未濟", 64),
+];
+
+// 卦名拼音，按文王卦序排列
+const PINYIN: [&str; 64] = [
+    "qián", "kūn", "zhūn", "méng", "xū", "sòng", "shī", "bǐ",
+    "xiǎo chù", "lǚ", "tài", "pǐ", "tóng rén", "dà yǒu", "qiān", "yù",
+    "suí", "gǔ", "lín", "guān", "shì kè", "bì", "bō", "fù",
+    "wú wàng", "dà chù", "yí", "dà guò", "kǎn", "lí", "xián", "héng",
+    "dùn", "dà zhuàng", "jìn", "míng yí", "jiā rén", "kuí", "jiǎn", "xiè",
+    "sǔn", "yì", "guài", "gòu", "cuì", "shēng", "kùn", "jǐng",
+    "gé", "dǐng", "zhèn", "gèn", "jiàn", "guī mèi", "fēng", "lǚ",
+    "xùn", "duì", "huàn", "jié", "zhōng fú", "xiǎo guò", "jì jì", "wèi jì",
+];
+
+impl Index for Hexagram {
+    /// 文王卦序，乾为1、坤为2……未济为64
+    fn index(&self) -> usize {
+        KING_WEN[self.upper as usize][self.lower as usize] as usize
+    }
+
+    // 序号按64循环，0视为64（未济）
+    fn from_index(index: usize) -> Self {
+        SEQUENCE[(index + 63) % 64]
+    }
+}
+
+impl ChineseName for Hexagram {
+    fn chinese_name(&self) -> &'static str {
+        NAMES[self.index() - 1]
+    }
+}
+
+impl Pinyin for Hexagram {
+    /// 卦名拼音。注意履与旅同为 lǚ，拼音不能唯一确定一卦
+    fn pinyin(&self) -> &'static str {
+        PINYIN[self.index() - 1]
+    }
+}
+
+impl Hexagram {
+    /// 六十四卦，按文王卦序排列
+    pub const ALL: [Hexagram; 64] = SEQUENCE;
+
+    /// 由上卦与下卦组成一卦
+    pub fn new(upper: Trigram, lower: Trigram) -> Self {
+        Self { upper, lower }
+    }
+
+    /// 由六爻组成一卦，从初爻到上爻
+    pub fn from_lines(lines: [YinYang; 6]) -> Self {
+        Self {
+            upper: Trigram::from_lines([lines[3], lines[4], lines[5]]),
+            lower: Trigram::from_lines([lines[0], lines[1], lines[2]]),
+        }
+    }
+
+    /// 获取六爻，从初爻到上爻
+    pub fn lines(&self) -> [YinYang; 6] {
+        let lower = self.lower.lines();
+        let upper = self.upper.lines();
+        [lower[0], lower[1], lower[2], upper[0], upper[1], upper[2]]
+    }
+
+    /// 以阳为1、阴为0转为六位二进制数，初爻为最高位
+    /// 此即邵雍先天次序所依据的数值：坤为0、剥为1……乾为63
+    pub fn to_binary(&self) -> u8 {
+        self.lines()
+            .iter()
+            .fold(0, |bits, line| (bits << 1) | (*line == YinYang::Yang) as u8)
+    }
+
+    /// 由六位二进制数得到一卦，初爻为最高位；超过63时返回 None
+    pub fn from_binary(bits: u8) -> Option<Self> {
+        if bits >= 64 {
+            return None;
+        }
+        let mut lines = [YinYang::Yin; 6];
+        for (i, line) in lines.iter_mut().enumerate() {
+            if bits & (1 << (5 - i)) != 0 {
+                *line = YinYang::Yang;
+            }
+        }
+        Some(Self::from_lines(lines))
+    }
+
+    /// 伏羲先天卦序，乾为1、夬为2……坤为64
+    pub fn fu_xi_index(&self) -> usize {
+        64 - self.to_binary() as usize
+    }
+
+    /// 由伏羲先天卦序得到一卦，序号按64循环，0视为64（坤）
+    pub fn from_fu_xi_index(index: usize) -> Self {
+        let position = (index + 63) % 64;
+        Self::from_binary(63 - position as u8).unwrap()
+    }
+
+    /// 互卦：以二、三、四爻为下卦，三、四、五爻为上卦
+    pub fn hu_gua(&self) -> Self {
+        let lines = self.lines();
+        Self {
+            upper: Trigram::from_lines([lines[2], lines[3], lines[4]]),
+            lower: Trigram::from_lines([lines[1], lines[2], lines[3]]),
+        }
+    }
+
+    /// 错卦（旁通）：六爻阴阳全部相反
+    pub fn cuo_gua(&self) -> Self {
+        Self::from_lines(self.lines().map(|line| line.opposite()))
+    }
+
+    /// 综卦（反卦）：六爻上下颠倒
+    pub fn zong_gua(&self) -> Self {
+        let mut lines = self.lines();
+        lines.reverse();
+        Self::from_lines(lines)
+    }
+
+    /// 变卦：动爻阴阳互变，moving 为动爻位置（1为初爻，6为上爻），重复出现的位置只变一次
+    ///
+    /// # Panics
+    ///
+    /// 动爻位置不在 1..=6 时 panic
+    pub fn bian_gua(&self, moving: &[usize]) -> Self {
+        let mut mask = [false; 6];
+        for &position in moving {
+            assert!((1..=6).contains(&position), "动爻位置 {} 超出范围 1..=6", position);
+            mask[position - 1] = true;
+        }
+        let mut lines = self.lines();
+        for (line, moved) in lines.iter_mut().zip(mask) {
+            if moved {
+                *line = line.opposite();
+            }
+        }
+        Self::from_lines(lines)
+    }
+}
+
+impl FromStr for Hexagram {
+    type Err = ParseError;
+
+    /// 解析卦名（简繁皆可，如"噬嗑"、"歸妹"）或文王卦序"1"…"64"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = parse::normalize(s);
+        if let Ok(index) = normalized.parse::<usize>() {
+            return if (1..=64).contains(&index) {
+                Ok(Self::from_index(index))
+            } else {
+                Err(ParseError::IndexOutOfRange { kind: "六十四卦", index, max: 64 })
+            };
+        }
+        NAMES
+            .iter()
+            .position(|name| *name == normalized)
+            .map(|position| SEQUENCE[position])
+            .or_else(|| {
+                VARIANTS
+                    .iter()
+                    .find(|(name, _)| *name == normalized)
+                    .map(|(_, number)| Self::from_index(*number as usize))
+            })
+            .ok_or_else(|| ParseError::UnknownName { kind: "六十四卦", input: s.to_string() })
+    }
+}
+
+impl Serialize for Hexagram {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match json::concept_style() {
+            ConceptStyle::Name => {
+                let mut state = serializer.serialize_struct("Hexagram", 2)?;
+                state.serialize_field("upper", &self.upper)?;
+                state.serialize_field("lower", &self.lower)?;
+                state.end()
+            }
+            ConceptStyle::Chinese => serializer.serialize_str(self.chinese_name()),
+            ConceptStyle::Pinyin => serializer.serialize_str(self.pinyin()),
+            ConceptStyle::Index => serializer.serialize_u64(self.index() as u64),
+            ConceptStyle::Rich => {
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_entry("chinese", self.chinese_name())?;
+                map.serialize_entry("pinyin", self.pinyin())?;
+                map.serialize_entry("index", &self.index())?;
+                map.serialize_entry("upper", &self.upper)?;
+                map.serialize_entry("lower", &self.lower)?;
+                map.serialize_entry("lines", &self.lines())?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Hexagram {
+    /// 接受卦名、文王卦序，或含 upper 与 lower 字段的对象
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HexagramVisitor;
+
+        impl<'de> Visitor<'de> for HexagramVisitor {
+            type Value = Hexagram;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("六十四卦的卦名、序号或 {upper, lower} 对象")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Hexagram, E> {
+                FromStrVisitor::<Hexagram>::new("六十四卦").visit_str(v)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Hexagram, E> {
+                self.visit_str(&v.to_string())
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Hexagram, E> {
+                self.visit_str(&v.to_string())
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Hexagram, A::Error> {
+                let mut upper: Option<Trigram> = None;
+                let mut lower: Option<Trigram> = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "upper" => upper = Some(map.next_value()?),
+                        "lower" => lower = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                let upper = upper.ok_or_else(|| de::Error::missing_field("upper"))?;
+                let lower = lower.ok_or_else(|| de::Error::missing_field("lower"))?;
+                Ok(Hexagram::new(upper, lower))
+            }
+        }
+
+        deserializer.deserialize_any(HexagramVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use crate::json::{to_json_with, JsonOptions};
+
+    fn hexagram(name: &str) -> Hexagram {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn test_king_wen_table() {
+        for (i, hexagram) in Hexagram::ALL.iter().enumerate() {
+            assert_eq!(hexagram.index(), i + 1);
+            assert_eq!(Hexagram::from_index(i + 1), *hexagram);
+            assert_eq!(Hexagram::from_lines(hexagram.lines()), *hexagram);
+            assert_eq!(Hexagram::from_binary(hexagram.to_binary()), Some(*hexagram));
+            assert_eq!(Hexagram::from_fu_xi_index(hexagram.fu_xi_index()), *hexagram);
+            assert_eq!(hexagram.chinese_name().parse::<Hexagram>(), Ok(*hexagram));
+            // 六十四卦两两不同
+            assert_eq!(Hexagram::ALL.iter().filter(|h| h.to_binary() == hexagram.to_binary()).count(), 1);
+        }
+        let tai = Hexagram::new(Trigram::Kun, Trigram::Qian);
+        assert_eq!(tai.chinese_name(), "泰");
+        assert_eq!(tai.index(), 11);
+        assert_eq!(hexagram("未济").lower, Trigram::Kan);
+        assert_eq!(hexagram("歸妹"), hexagram("54"));
+        assert!("65".parse::<Hexagram>().is_err());
+        assert_eq!(Hexagram::from_binary(64), None);
+    }
+
+    #[test]
+    fn test_fu_xi_order() {
+        let names: Vec<_> = (1..=8).map(|i| Hexagram::from_fu_xi_index(i).chinese_name()).collect();
+        assert_eq!(names, ["乾", "夬", "大有", "大壮", "小畜", "需", "大畜", "泰"]);
+        assert_eq!(hexagram("坤").to_binary(), 0);
+        assert_eq!(hexagram("剥").to_binary(), 1);
+        assert_eq!(hexagram("复").to_binary(), 32);
+        assert_eq!(hexagram("坤").fu_xi_index(), 64);
+    }
+
+    #[test]
+    fn test_derived_hexagrams() {
+        assert_eq!(hexagram("乾").hu_gua(), hexagram("乾"));
+        assert_eq!(hexagram("屯").hu_gua(), hexagram("剥"));
+        assert_eq!(hexagram("既济").hu_gua(), hexagram("未济"));
+        assert_eq!(hexagram("乾").cuo_gua(), hexagram("坤"));
+        assert_eq!(hexagram("屯").cuo_gua(), hexagram("鼎"));
+        assert_eq!(hexagram("屯").zong_gua(), hexagram("蒙"));
+        assert_eq!(hexagram("泰").zong_gua(), hexagram("否"));
+        assert_eq!(hexagram("乾").bian_gua(&[1]), hexagram("姤"));
+        assert_eq!(hexagram("乾").bian_gua(&[6, 1, 1]), hexagram("大过"));
+        assert_eq!(hexagram("坤").bian_gua(&[]), hexagram("坤"));
+        for hexagram in Hexagram::ALL {
+            assert_eq!(hexagram.cuo_gua().cuo_gua(), hexagram);
+            assert_eq!(hexagram.zong_gua().zong_gua(), hexagram);
+            assert_eq!(hexagram.bian_gua(&[1, 2, 3, 4, 5, 6]), hexagram.cuo_gua());
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let ding = hexagram("鼎");
+        for style in [ConceptStyle::Name, ConceptStyle::Chinese, ConceptStyle::Index, ConceptStyle::Rich] {
+            let json = to_json_with(&ding, &JsonOptions::with_style(style)).unwrap();
+            assert_eq!(serde_json::from_str::<Hexagram>(&json).unwrap(), ding);
+        }
+        assert_eq!(serde_json::to_string(&ding).unwrap(), r#"{"upper":"Li","lower":"Xun"}"#);
+    }
+}
@@ -0,0 +1,153 @@
+// src/concepts/luopan.rs - 罗盘外圈
+// 在二十四山之外，给出穿山七十二龙、一百二十分金、透地六十龙与周天分度各层对任意方位的读数
+use serde::Serialize;
+use crate::concepts::gan_zhi::{EarthlyBranch, GanZhi, HeavenlyStem};
+use crate::concepts::mountain::Mountain;
+use crate::concepts::traits::{ChineseName, Index};
+
+// 归一到 0..360 后自壬山起点（337.5°）量起的度数
+fn from_ren(degree: f64) -> f64 {
+    ((degree + 22.5) % 360.0 + 360.0) % 360.0
+}
+
+// 以地支与序号（0..5）取该支的第几位干支：阳支配甲丙戊庚壬，阴支配乙丁己辛癸
+fn gan_zhi_of(branch: EarthlyBranch, k: usize) -> GanZhi {
+    let stem = HeavenlyStem::from_index(k * 2 + 1 + (branch.index() + 1) % 2);
+    GanZhi::new(stem, branch).unwrap()
+}
+
+/// 穿山七十二龙的一格，每格5°
+/// 每一干支双山（如壬子）三十度分六格：首格为干维空位，其后依次为该支的五位干支
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Dragon {
+    /// 空位，以所在的干维山命名
+    Kong(Mountain),
+    /// 干支龙
+    GanZhi(GanZhi),
+}
+
+impl ChineseName for Dragon {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Dragon::Kong(mountain) => mountain.chinese_name(),
+            Dragon::GanZhi(gan_zhi) => gan_zhi.chinese_name(),
+        }
+    }
+}
+
+impl Dragon {
+    /// 方位角所在的穿山龙
+    pub fn at(degree: f64) -> Self {
+        let val = from_ren(degree);
+        let group = ((val / 30.0) as usize).min(11);
+        let slot = (((val - group as f64 * 30.0) / 5.0) as usize).min(5);
+        if slot == 0 {
+            Dragon::Kong(Mountain::ALL[group * 2])
+        } else {
+            Dragon::GanZhi(gan_zhi_of(EarthlyBranch::from_index(group + 1), slot - 1))
+        }
+    }
+}
+
+/// 一百二十分金：每山五格，每格3°，干维山与其后地支山同配该支的五位干支
+pub fn fen_jin(degree: f64) -> GanZhi {
+    let (mountain, offset) = Mountain::from_degree(degree);
+    gan_zhi_of(mountain.branch(), ((offset / 3.0) as usize).min(4))
+}
+
+/// 透地六十龙：每一干支双山分五格，每格6°，依次为该支的五位干支
+pub fn tou_di(degree: f64) -> GanZhi {
+    let val = from_ren(degree);
+    let group = ((val / 30.0) as usize).min(11);
+    let slot = (((val - group as f64 * 30.0) / 6.0) as usize).min(4);
+    gan_zhi_of(EarthlyBranch::from_index(group + 1), slot)
+}
+
+/// 分度：自正北起将周天等分为divisions格，返回所在格的序号（从1开始），如360分度、280分度
+pub fn division(degree: f64, divisions: usize) -> usize {
+    let val = (degree % 360.0 + 360.0) % 360.0;
+    ((val / 360.0 * divisions as f64) as usize).min(divisions - 1) + 1
+}
+
+/// 罗盘各层在某一方位的读数
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct LuopanReading {
+    /// 方位角（度，正北为0，顺时针）
+    pub bearing: f64,
+    /// 二十四山
+    pub mountain: Mountain,
+    /// 在该山内的度数偏移（0..15）
+    pub mountain_offset: f64,
+    /// 穿山七十二龙
+    pub dragon: Dragon,
+    /// 一百二十分金
+    pub fen_jin: GanZhi,
+    /// 透地六十龙
+    pub tou_di: GanZhi,
+    /// 二百八十分度
+    pub degree_280: usize,
+    /// 三百六十分度
+    pub degree_360: usize,
+}
+
+impl LuopanReading {
+    /// 读取方位角上各层的标签
+    pub fn at(bearing: f64) -> Self {
+        let (mountain, mountain_offset) = Mountain::from_degree(bearing);
+        LuopanReading {
+            bearing,
+            mountain,
+            mountain_offset,
+            dragon: Dragon::at(bearing),
+            fen_jin: fen_jin(bearing),
+            tou_di: tou_di(bearing),
+            degree_280: division(bearing, 280),
+            degree_360: division(bearing, 360),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gan_zhi(name: &str) -> GanZhi {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn test_rings() {
+        // 壬子双山：壬山首格空，其后甲子、丙子；子山戊子、庚子、壬子
+        assert_eq!(Dragon::at(340.0), Dragon::Kong(Mountain::Ren));
+        assert_eq!(Dragon::at(343.0), Dragon::GanZhi(gan_zhi("甲子")));
+        assert_eq!(Dragon::at(355.0), Dragon::GanZhi(gan_zhi("戊子")));
+        assert_eq!(Dragon::at(0.0), Dragon::GanZhi(gan_zhi("庚子")));
+        assert_eq!(Dragon::at(10.0), Dragon::Kong(Mountain::Gui));
+        assert_eq!(Dragon::at(15.0).chinese_name(), "乙丑");
+        // 七十二龙中十二格为空，其余六十格为干支
+        let mut count = 0;
+        for slot in 0..72 {
+            if let Dragon::GanZhi(_) = Dragon::at(337.5 + slot as f64 * 5.0 + 2.5) {
+                count += 1;
+            }
+        }
+        assert_eq!(count, 60);
+
+        // 子山五格：甲子、丙子、戊子、庚子、壬子，壬山同
+        assert_eq!(fen_jin(0.0), gan_zhi("戊子"));
+        assert_eq!(fen_jin(357.0), gan_zhi("丙子"));
+        assert_eq!(fen_jin(345.0), gan_zhi("戊子"));
+        assert_eq!(fen_jin(180.0), gan_zhi("戊午"));
+        assert_eq!(tou_di(338.0), gan_zhi("甲子"));
+        assert_eq!(tou_di(6.0), gan_zhi("壬子"));
+
+        assert_eq!(division(0.0, 360), 1);
+        assert_eq!(division(359.9, 360), 360);
+        assert_eq!(division(-0.5, 280), 280);
+
+        let reading = LuopanReading::at(45.0);
+        assert_eq!(reading.mountain, Mountain::Gen);
+        assert_eq!(reading.mountain_offset, 7.5);
+        assert_eq!(reading.degree_360, 46);
+    }
+}
@@ -0,0 +1,381 @@
+// src/concepts/mountain.rs - 二十四山模块
+// 八干、十二支、四维卦分周天为二十四山，每山15度，子山居正北
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+use crate::concepts::bagua::Trigram;
+use crate::concepts::direction::Direction;
+use crate::concepts::gan_zhi::{EarthlyBranch, HeavenlyStem};
+use crate::concepts::wu_xing::WuXing;
+use crate::concepts::yinyang::YinYang;
+use crate::concepts::traits::{ChineseName, Pinyin, Index, Iter, YinYangTrait, WuXingTrait};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::json;
+
+/// 二十四山枚举，自壬山起按顺时针排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mountain {
+    /// 壬
+    Ren,
+    /// 子
+    Zi,
+    /// 癸
+    Gui,
+    /// 丑
+    Chou,
+    /// 艮
+    Gen,
+    /// 寅
+    Yin,
+    /// 甲
+    Jia,
+    /// 卯
+    Mao,
+    /// 乙
+    Yi,
+    /// 辰
+    Chen,
+    /// 巽
+    Xun,
+    /// 巳
+    Si,
+    /// 丙
+    Bing,
+    /// 午
+    Wu,
+    /// 丁
+    Ding,
+    /// 未
+    Wei,
+    /// 坤
+    Kun,
+    /// 申
+    Shen,
+    /// 庚
+    Geng,
+    /// 酉
+    You,
+    /// 辛
+    Xin,
+    /// 戌
+    Xu,
+    /// 乾
+    Qian,
+    /// 亥
+    Hai,
+}
+
+/// 山名的来源：八干、十二支或四维卦（戊己居中，不占山）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MountainOrigin {
+    /// 天干
+    Stem(HeavenlyStem),
+    /// 地支
+    Branch(EarthlyBranch),
+    /// 四维卦：乾坤艮巽
+    Trigram(Trigram),
+}
+
+/// 三元龙：每卦三山依次为地元、天元、人元
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum YuanLong {
+    /// 地元龙：甲庚壬丙、辰戌丑未
+    Di,
+    /// 天元龙：子午卯酉、乾坤艮巽
+    Tian,
+    /// 人元龙：乙辛丁癸、寅申巳亥
+    Ren,
+}
+
+impl ChineseName for YuanLong {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            YuanLong::Di => "地元龙",
+            YuanLong::Tian => "天元龙",
+            YuanLong::Ren => "人元龙",
+        }
+    }
+}
+
+impl ChineseName for Mountain {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Mountain::Ren => "壬",
+            Mountain::Zi => "子",
+            Mountain::Gui => "癸",
+            Mountain::Chou => "丑",
+            Mountain::Gen => "艮",
+            Mountain::Yin => "寅",
+            Mountain::Jia => "甲",
+            Mountain::Mao => "卯",
+            Mountain::Yi => "乙",
+            Mountain::Chen => "辰",
+            Mountain::Xun => "巽",
+            Mountain::Si => "巳",
+            Mountain::Bing => "丙",
+            Mountain::Wu => "午",
+            Mountain::Ding => "丁",
+            Mountain::Wei => "未",
+            Mountain::Kun => "坤",
+            Mountain::Shen => "申",
+            Mountain::Geng => "庚",
+            Mountain::You => "酉",
+            Mountain::Xin => "辛",
+            Mountain::Xu => "戌",
+            Mountain::Qian => "乾",
+            Mountain::Hai => "亥",
+        }
+    }
+}
+
+impl Pinyin for Mountain {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            Mountain::Ren => "rén",
+            Mountain::Zi => "zǐ",
+            Mountain::Gui => "guǐ",
+            Mountain::Chou => "chǒu",
+            Mountain::Gen => "gèn",
+            Mountain::Yin => "yín",
+            Mountain::Jia => "jiǎ",
+            Mountain::Mao => "mǎo",
+            Mountain::Yi => "yǐ",
+            Mountain::Chen => "chén",
+            Mountain::Xun => "xùn",
+            Mountain::Si => "sì",
+            Mountain::Bing => "bǐng",
+            Mountain::Wu => "wǔ",
+            Mountain::Ding => "dīng",
+            Mountain::Wei => "wèi",
+            Mountain::Kun => "kūn",
+            Mountain::Shen => "shēn",
+            Mountain::Geng => "gēng",
+            Mountain::You => "yǒu",
+            Mountain::Xin => "xīn",
+            Mountain::Xu => "xū",
+            Mountain::Qian => "qián",
+            Mountain::Hai => "hài",
+        }
+    }
+}
+
+impl Index for Mountain {
+    // 序号按24循环，0视为24（亥）
+    fn from_index(index: usize) -> Self {
+        Self::ALL[(index + 23) % 24]
+    }
+
+    fn index(&self) -> usize {
+        *self as usize + 1
+    }
+}
+
+impl Iter for Mountain {
+    type Item = Self;
+
+    /// 顺时针的下一山
+    fn next(&self) -> Self::Item {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// 逆时针的下一山
+    fn prev(&self) -> Self::Item {
+        Self::from_index(self.index() + 23)
+    }
+}
+
+impl YinYangTrait for Mountain {
+    /// 三元龙阴阳：四正卦（坎震离兑）三山为阳阴阴，四隅卦（艮巽坤乾）三山为阴阳阳
+    fn yinyang(&self) -> YinYang {
+        let cardinal = matches!(self.trigram(), Trigram::Kan | Trigram::Zhen | Trigram::Li | Trigram::Dui);
+        if cardinal == (self.yuan_long() == YuanLong::Di) {
+            YinYang::Yang
+        } else {
+            YinYang::Yin
+        }
+    }
+}
+
+impl WuXingTrait for Mountain {
+    /// 正五行，即山名所出干、支、卦的五行
+    fn wuxing(&self) -> WuXing {
+        match self.origin() {
+            MountainOrigin::Stem(stem) => stem.wuxing(),
+            MountainOrigin::Branch(branch) => branch.wuxing(),
+            MountainOrigin::Trigram(trigram) => trigram.wuxing(),
+        }
+    }
+}
+
+impl Mountain {
+    /// 二十四山，自壬山起按顺时针排列
+    pub const ALL: [Mountain; 24] = [
+        Mountain::Ren, Mountain::Zi, Mountain::Gui, Mountain::Chou, Mountain::Gen, Mountain::Yin,
+        Mountain::Jia, Mountain::Mao, Mountain::Yi, Mountain::Chen, Mountain::Xun, Mountain::Si,
+        Mountain::Bing, Mountain::Wu, Mountain::Ding, Mountain::Wei, Mountain::Kun, Mountain::Shen,
+        Mountain::Geng, Mountain::You, Mountain::Xin, Mountain::Xu, Mountain::Qian, Mountain::Hai,
+    ];
+
+    /// 由方位角（度，正北为0，顺时针）求所在的山及在该山内的度数偏移（0..15）
+    pub fn from_degree(degree: f64) -> (Self, f64) {
+        // core 中的 f64 没有 rem_euclid，手动归一到 0..360；壬山起于337.5°
+        let val = ((degree + 22.5) % 360.0 + 360.0) % 360.0;
+        let index = ((val / 15.0) as usize).min(23);
+        (Self::ALL[index], val - index as f64 * 15.0)
+    }
+
+    /// 山的中线方位角（度），子山为0
+    pub fn azimuth(&self) -> f64 {
+        ((self.index() + 22) % 24) as f64 * 15.0
+    }
+
+    /// 起始方位角（度），即逆时针一侧的山界
+    pub fn start_degree(&self) -> f64 {
+        (self.azimuth() + 352.5) % 360.0
+    }
+
+    /// 对山：相隔180°，如子午、壬丙
+    pub fn opposite(&self) -> Self {
+        Self::from_index(self.index() + 12)
+    }
+
+    /// 山名所出：八干、十二支或四维卦
+    pub fn origin(&self) -> MountainOrigin {
+        match self {
+            Mountain::Ren => MountainOrigin::Stem(HeavenlyStem::Ren),
+            Mountain::Gui => MountainOrigin::Stem(HeavenlyStem::Gui),
+            Mountain::Jia => MountainOrigin::Stem(HeavenlyStem::Jia),
+            Mountain::Yi => MountainOrigin::Stem(HeavenlyStem::Yi),
+            Mountain::Bing => MountainOrigin::Stem(HeavenlyStem::Bing),
+            Mountain::Ding => MountainOrigin::Stem(HeavenlyStem::Ding),
+            Mountain::Geng => MountainOrigin::Stem(HeavenlyStem::Geng),
+            Mountain::Xin => MountainOrigin::Stem(HeavenlyStem::Xin),
+            Mountain::Gen => MountainOrigin::Trigram(Trigram::Gen),
+            Mountain::Xun => MountainOrigin::Trigram(Trigram::Xun),
+            Mountain::Kun => MountainOrigin::Trigram(Trigram::Kun),
+            Mountain::Qian => MountainOrigin::Trigram(Trigram::Qian),
+            _ => MountainOrigin::Branch(self.branch()),
+        }
+    }
+
+    /// 双山五行所配的地支：干维与其后的地支同宫，如壬子、癸丑、艮寅
+    pub fn branch(&self) -> EarthlyBranch {
+        EarthlyBranch::from_index((*self as usize) / 2 + 1)
+    }
+
+    /// 所在的后天八卦宫，每宫三山，如坎宫壬子癸
+    pub fn trigram(&self) -> Trigram {
+        Trigram::from_hou_tian_direction(self.direction())
+    }
+
+    /// 所在的八方
+    pub fn direction(&self) -> Direction {
+        Direction::from_index((*self as usize) / 3 + 1)
+    }
+
+    /// 三元龙
+    pub fn yuan_long(&self) -> YuanLong {
+        match (*self as usize) % 3 {
+            0 => YuanLong::Di,
+            1 => YuanLong::Tian,
+            _ => YuanLong::Ren,
+        }
+    }
+
+    /// 某卦宫中某元龙的山
+    pub fn from_trigram(trigram: Trigram, yuan_long: YuanLong) -> Self {
+        let palace = trigram.hou_tian_direction().index() - 1;
+        Self::ALL[palace * 3 + yuan_long as usize]
+    }
+
+    // 可解析的别名：中文与拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Mountain::Ren => &["壬", "ren"],
+            Mountain::Zi => &["子", "zi"],
+            Mountain::Gui => &["癸", "gui"],
+            Mountain::Chou => &["丑", "chou"],
+            Mountain::Gen => &["艮", "gen"],
+            Mountain::Yin => &["寅", "yin"],
+            Mountain::Jia => &["甲", "jia"],
+            Mountain::Mao => &["卯", "mao"],
+            Mountain::Yi => &["乙", "yi"],
+            Mountain::Chen => &["辰", "chen"],
+            Mountain::Xun => &["巽", "xun"],
+            Mountain::Si => &["巳", "si"],
+            Mountain::Bing => &["丙", "bing"],
+            Mountain::Wu => &["午", "wu"],
+            Mountain::Ding => &["丁", "ding"],
+            Mountain::Wei => &["未", "wei"],
+            Mountain::Kun => &["坤", "kun"],
+            Mountain::Shen => &["申", "shen"],
+            Mountain::Geng => &["庚", "geng"],
+            Mountain::You => &["酉", "you"],
+            Mountain::Xin => &["辛", "xin"],
+            Mountain::Xu => &["戌", "xu"],
+            Mountain::Qian => &["乾", "qian"],
+            Mountain::Hai => &["亥", "hai"],
+        }
+    }
+}
+
+impl FromStr for Mountain {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "二十四山", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(Mountain, "二十四山");
+
+impl Serialize for Mountain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("azimuth", &self.azimuth())?;
+            map.serialize_entry("trigram", &self.trigram())?;
+            map.serialize_entry("yuan_long", &self.yuan_long())?;
+            map.serialize_entry("polarity", &self.yinyang())?;
+            map.serialize_entry("element", &self.wuxing())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degree() {
+        assert_eq!(Mountain::from_degree(0.0), (Mountain::Zi, 7.5));
+        assert_eq!(Mountain::from_degree(337.5), (Mountain::Ren, 0.0));
+        assert_eq!(Mountain::from_degree(-10.0).0, Mountain::Ren);
+        assert_eq!(Mountain::from_degree(180.0).0, Mountain::Wu);
+        assert_eq!(Mountain::from_degree(45.0).0, Mountain::Gen);
+        for mountain in Mountain::ALL {
+            assert_eq!(Mountain::from_degree(mountain.azimuth()), (mountain, 7.5));
+            assert_eq!(Mountain::from_degree(mountain.start_degree()).0, mountain);
+            assert_eq!(mountain.opposite().opposite(), mountain);
+            assert_eq!(Mountain::from_trigram(mountain.trigram(), mountain.yuan_long()), mountain);
+            assert_eq!(mountain.chinese_name().parse::<Mountain>(), Ok(mountain));
+        }
+    }
+
+    #[test]
+    fn test_attributes() {
+        assert_eq!(Mountain::Zi.trigram(), Trigram::Kan);
+        assert_eq!(Mountain::Gen.origin(), MountainOrigin::Trigram(Trigram::Gen));
+        assert_eq!(Mountain::Ren.origin(), MountainOrigin::Stem(HeavenlyStem::Ren));
+        assert_eq!(Mountain::Ren.branch(), EarthlyBranch::Zi);
+        assert_eq!(Mountain::Qian.branch(), EarthlyBranch::Hai);
+        assert_eq!(Mountain::Mao.yuan_long(), YuanLong::Tian);
+        assert_eq!(Mountain::Shen.yuan_long(), YuanLong::Ren);
+        // 子为阴、壬为阳、艮为阳、丑为阴
+        assert_eq!(Mountain::Zi.yinyang(), YinYang::Yin);
+        assert_eq!(Mountain::Ren.yinyang(), YinYang::Yang);
+        assert_eq!(Mountain::Gen.yinyang(), YinYang::Yang);
+        assert_eq!(Mountain::Chou.yinyang(), YinYang::Yin);
+        assert_eq!(Mountain::Xun.wuxing(), WuXing::Wood);
+        assert_eq!(Mountain::Wei.wuxing(), WuXing::Earth);
+    }
+}
@@ -0,0 +1,498 @@
+// src/concepts/nine_star.rs - 九星与洛书九宫模块
+// 一白、二黑……九紫，按洛书轨迹飞布九宫，并推算年、月、日、时的值星
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+use crate::concepts::bagua::Trigram;
+use crate::concepts::direction::Direction;
+use crate::concepts::gan_zhi::EarthlyBranch;
+use crate::concepts::wu_xing::WuXing;
+use crate::concepts::traits::{ChineseName, Pinyin, Index, Iter, WuXingTrait};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::json;
+
+/// 九星枚举，按一白至九紫排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NineStar {
+    /// 一白水星（贪狼）
+    YiBai,
+    /// 二黑土星（巨门）
+    ErHei,
+    /// 三碧木星（禄存）
+    SanBi,
+    /// 四绿木星（文曲）
+    SiLu,
+    /// 五黄土星（廉贞）
+    WuHuang,
+    /// 六白金星（武曲）
+    LiuBai,
+    /// 七赤金星（破军）
+    QiChi,
+    /// 八白土星（左辅）
+    BaBai,
+    /// 九紫火星（右弼）
+    JiuZi,
+}
+
+impl ChineseName for NineStar {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            NineStar::YiBai => "一白",
+            NineStar::ErHei => "二黑",
+            NineStar::SanBi => "三碧",
+            NineStar::SiLu => "四绿",
+            NineStar::WuHuang => "五黄",
+            NineStar::LiuBai => "六白",
+            NineStar::QiChi => "七赤",
+            NineStar::BaBai => "八白",
+            NineStar::JiuZi => "九紫",
+        }
+    }
+}
+
+impl Pinyin for NineStar {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            NineStar::YiBai => "yī bái",
+            NineStar::ErHei => "èr hēi",
+            NineStar::SanBi => "sān bì",
+            NineStar::SiLu => "sì lǜ",
+            NineStar::WuHuang => "wǔ huáng",
+            NineStar::LiuBai => "liù bái",
+            NineStar::QiChi => "qī chì",
+            NineStar::BaBai => "bā bái",
+            NineStar::JiuZi => "jiǔ zǐ",
+        }
+    }
+}
+
+impl Index for NineStar {
+    // 序号按9循环，0视为9（九紫）
+    fn from_index(index: usize) -> Self {
+        Self::ALL[(index + 8) % 9]
+    }
+
+    fn index(&self) -> usize {
+        *self as usize + 1
+    }
+}
+
+impl Iter for NineStar {
+    type Item = Self;
+
+    /// 顺数的下一星，九紫之后为一白
+    fn next(&self) -> Self::Item {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// 逆数的下一星，一白之前为九紫
+    fn prev(&self) -> Self::Item {
+        Self::from_index(self.index() + 8)
+    }
+}
+
+impl WuXingTrait for NineStar {
+    fn wuxing(&self) -> WuXing {
+        match self {
+            NineStar::YiBai => WuXing::Water,
+            NineStar::ErHei | NineStar::WuHuang | NineStar::BaBai => WuXing::Earth,
+            NineStar::SanBi | NineStar::SiLu => WuXing::Wood,
+            NineStar::LiuBai | NineStar::QiChi => WuXing::Metal,
+            NineStar::JiuZi => WuXing::Fire,
+        }
+    }
+}
+
+impl NineStar {
+    /// 九星，按一白至九紫排列
+    pub const ALL: [NineStar; 9] = [
+        NineStar::YiBai, NineStar::ErHei, NineStar::SanBi, NineStar::SiLu, NineStar::WuHuang,
+        NineStar::LiuBai, NineStar::QiChi, NineStar::BaBai, NineStar::JiuZi,
+    ];
+
+    /// 颜色：白、黑、碧、绿、黄、白、赤、白、紫
+    pub fn color(&self) -> &'static str {
+        match self {
+            NineStar::YiBai | NineStar::LiuBai | NineStar::BaBai => "白",
+            NineStar::ErHei => "黑",
+            NineStar::SanBi => "碧",
+            NineStar::SiLu => "绿",
+            NineStar::WuHuang => "黄",
+            NineStar::QiChi => "赤",
+            NineStar::JiuZi => "紫",
+        }
+    }
+
+    /// 北斗星名：贪狼、巨门、禄存、文曲、廉贞、武曲、破军、左辅、右弼
+    pub fn star_name(&self) -> &'static str {
+        match self {
+            NineStar::YiBai => "贪狼",
+            NineStar::ErHei => "巨门",
+            NineStar::SanBi => "禄存",
+            NineStar::SiLu => "文曲",
+            NineStar::WuHuang => "廉贞",
+            NineStar::LiuBai => "武曲",
+            NineStar::QiChi => "破军",
+            NineStar::BaBai => "左辅",
+            NineStar::JiuZi => "右弼",
+        }
+    }
+
+    /// 本位：九星在洛书中的原始宫位，即与星数相同的宫
+    pub fn home_palace(&self) -> NinePalace {
+        NinePalace::from_index(self.index())
+    }
+
+    /// 以本星入中宫，按洛书轨迹顺飞（forward）或逆飞
+    pub fn fly(&self, forward: bool) -> FlyingStars {
+        FlyingStars::new(*self, forward)
+    }
+
+    /// 年紫白：以立春为岁首，三元九运中每年退一星
+    /// 如2024年三碧、2025年二黑、2026年一白入中
+    pub fn of_year(year: i32) -> Self {
+        Self::from_index((11 - year.rem_euclid(9)) as usize)
+    }
+
+    /// 月紫白：以节令分月，子午卯酉年正月（寅月）八白、辰戌丑未年五黄、寅申巳亥年二黑，逐月退一星
+    pub fn of_month(year: EarthlyBranch, month: EarthlyBranch) -> Self {
+        let first = match year.index() % 3 {
+            1 => 8,
+            2 => 5,
+            _ => 2,
+        };
+        // 寅月为正月
+        let offset = (month.index() + 9) % 12;
+        Self::from_index(first + 9 * 2 - offset)
+    }
+
+    /// 日紫白：冬至前后最近的甲子日起阳遁，一白顺行；夏至前后最近的甲子日起阴遁，九紫逆行
+    /// elapsed 为距起遁甲子日的天数（起遁当日为0）；由儒略日直接推算见 calendar::jie_qi::day_star
+    pub fn of_day(dun: Dun, elapsed: u32) -> Self {
+        let step = (elapsed % 9) as usize;
+        match dun {
+            Dun::Yang => Self::from_index(1 + step),
+            Dun::Yin => Self::from_index(9 + 9 - step),
+        }
+    }
+
+    /// 时紫白：阳遁子午卯酉日子时起一白、辰戌丑未日起四绿、寅申巳亥日起七赤，顺行
+    /// 阴遁子午卯酉日子时起九紫、辰戌丑未日起六白、寅申巳亥日起三碧，逆行
+    /// 由儒略日直接推算见 calendar::jie_qi::hour_star
+    pub fn of_hour(dun: Dun, day: EarthlyBranch, hour: EarthlyBranch) -> Self {
+        let group = (day.index() - 1) % 3;
+        let step = hour.index() - 1;
+        match dun {
+            Dun::Yang => Self::from_index(1 + group * 3 + step),
+            Dun::Yin => Self::from_index(9 * 3 + 9 - group * 3 - step),
+        }
+    }
+
+    // 可解析的别名：简繁中文、星名与拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            NineStar::YiBai => &["一白", "一白水星", "贪狼", "貪狼", "yibai"],
+            NineStar::ErHei => &["二黑", "二黑土星", "巨门", "巨門", "erhei"],
+            NineStar::SanBi => &["三碧", "三碧木星", "禄存", "祿存", "sanbi"],
+            NineStar::SiLu => &["四绿", "四綠", "四绿木星", "文曲", "silv", "silu"],
+            NineStar::WuHuang => &["五黄", "五黃", "五黄土星", "廉贞", "廉貞", "wuhuang"],
+            NineStar::LiuBai => &["六白", "六白金星", "武曲", "liubai"],
+            NineStar::QiChi => &["七赤", "七赤金星", "破军", "破軍", "qichi"],
+            NineStar::BaBai => &["八白", "八白土星", "左辅", "左輔", "babai"],
+            NineStar::JiuZi => &["九紫", "九紫火星", "右弼", "jiuzi"],
+        }
+    }
+}
+
+impl FromStr for NineStar {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "九星", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(NineStar, "九星");
+
+impl Serialize for NineStar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("element", &self.wuxing())?;
+            map.serialize_entry("color", self.color())
+        })
+    }
+}
+
+/// 阴阳遁：冬至至夏至为阳遁，夏至至冬至为阴遁
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Dun {
+    /// 阳遁，九星顺行
+    Yang,
+    /// 阴遁，九星逆行
+    Yin,
+}
+
+impl ChineseName for Dun {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Dun::Yang => "阳遁",
+            Dun::Yin => "阴遁",
+        }
+    }
+}
+
+impl Dun {
+    /// 由太阳视黄经（回归黄道，度）判断阴阳遁：冬至（270°）起为阳遁，夏至（90°）起为阴遁
+    pub fn from_solar_longitude(longitude: f64) -> Self {
+        // core 中的 f64 没有 rem_euclid，手动归一到 0..360
+        if (90.0..270.0).contains(&((longitude % 360.0 + 360.0) % 360.0)) {
+            Dun::Yin
+        } else {
+            Dun::Yang
+        }
+    }
+}
+
+/// 洛书九宫，按宫数一至九排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NinePalace {
+    /// 一宫坎，正北
+    Kan,
+    /// 二宫坤，西南
+    Kun,
+    /// 三宫震，正东
+    Zhen,
+    /// 四宫巽，东南
+    Xun,
+    /// 五宫中
+    Center,
+    /// 六宫乾，西北
+    Qian,
+    /// 七宫兑，正西
+    Dui,
+    /// 八宫艮，东北
+    Gen,
+    /// 九宫离，正南
+    Li,
+}
+
+impl ChineseName for NinePalace {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            NinePalace::Kan => "坎宫",
+            NinePalace::Kun => "坤宫",
+            NinePalace::Zhen => "震宫",
+            NinePalace::Xun => "巽宫",
+            NinePalace::Center => "中宫",
+            NinePalace::Qian => "乾宫",
+            NinePalace::Dui => "兑宫",
+            NinePalace::Gen => "艮宫",
+            NinePalace::Li => "离宫",
+        }
+    }
+}
+
+impl Pinyin for NinePalace {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            NinePalace::Kan => "kǎn gōng",
+            NinePalace::Kun => "kūn gōng",
+            NinePalace::Zhen => "zhèn gōng",
+            NinePalace::Xun => "xùn gōng",
+            NinePalace::Center => "zhōng gōng",
+            NinePalace::Qian => "qián gōng",
+            NinePalace::Dui => "duì gōng",
+            NinePalace::Gen => "gèn gōng",
+            NinePalace::Li => "lí gōng",
+        }
+    }
+}
+
+impl Index for NinePalace {
+    // 序号即洛书宫数，按9循环，0视为9（离宫）
+    fn from_index(index: usize) -> Self {
+        Self::ALL[(index + 8) % 9]
+    }
+
+    fn index(&self) -> usize {
+        *self as usize + 1
+    }
+}
+
+impl NinePalace {
+    /// 九宫，按宫数一至九排列
+    pub const ALL: [NinePalace; 9] = [
+        NinePalace::Kan, NinePalace::Kun, NinePalace::Zhen, NinePalace::Xun, NinePalace::Center,
+        NinePalace::Qian, NinePalace::Dui, NinePalace::Gen, NinePalace::Li,
+    ];
+
+    /// 洛书飞星轨迹：中五、乾六、兑七、艮八、离九、坎一、坤二、震三、巽四
+    pub const FLIGHT: [NinePalace; 9] = [
+        NinePalace::Center, NinePalace::Qian, NinePalace::Dui, NinePalace::Gen, NinePalace::Li,
+        NinePalace::Kan, NinePalace::Kun, NinePalace::Zhen, NinePalace::Xun,
+    ];
+
+    /// 后天八卦中位于该宫的卦，中宫无卦
+    pub fn trigram(&self) -> Option<Trigram> {
+        Trigram::from_hou_tian_index(self.index())
+    }
+
+    /// 宫位所在方位，中宫无方位
+    pub fn direction(&self) -> Option<Direction> {
+        self.trigram().map(|trigram| trigram.hou_tian_direction())
+    }
+
+    /// 方位所在的宫
+    pub fn from_direction(direction: Direction) -> Self {
+        Self::from_index(Trigram::from_hou_tian_direction(direction).hou_tian_index())
+    }
+
+    /// 对宫：两宫之数相加为十，中宫对自身
+    pub fn opposite(&self) -> Self {
+        Self::from_index(10 - self.index())
+    }
+
+    /// 在飞星轨迹中的步数，中宫为0
+    pub fn flight_step(&self) -> usize {
+        (self.index() + 4) % 9
+    }
+
+    // 可解析的别名：宫名、卦名与方位
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            NinePalace::Kan => &["坎宫", "坎", "kan", "北"],
+            NinePalace::Kun => &["坤宫", "坤", "kun", "西南"],
+            NinePalace::Zhen => &["震宫", "震", "zhen", "东", "東"],
+            NinePalace::Xun => &["巽宫", "巽", "xun", "东南", "東南"],
+            NinePalace::Center => &["中宫", "中宮", "中", "zhong", "zhonggong", "center"],
+            NinePalace::Qian => &["乾宫", "乾", "qian", "西北"],
+            NinePalace::Dui => &["兑宫", "兌宮", "兑", "dui", "西"],
+            NinePalace::Gen => &["艮宫", "艮", "gen", "东北", "東北"],
+            NinePalace::Li => &["离宫", "離宮", "离", "離", "li", "南"],
+        }
+    }
+}
+
+impl FromStr for NinePalace {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "九宫", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(NinePalace, "九宫");
+
+impl Serialize for NinePalace {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("direction", &self.direction())
+        })
+    }
+}
+
+/// 飞星盘：某星入中后按洛书轨迹飞布九宫
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FlyingStars {
+    /// 入中之星
+    pub center: NineStar,
+    /// 是否顺飞
+    pub forward: bool,
+    /// 各宫之星，按宫数一至九排列
+    pub stars: [NineStar; 9],
+}
+
+impl FlyingStars {
+    /// 以center入中，顺飞时每进一宫星数加一，逆飞时减一
+    pub fn new(center: NineStar, forward: bool) -> Self {
+        let stars = NinePalace::ALL.map(|palace| {
+            let step = palace.flight_step();
+            if forward {
+                NineStar::from_index(center.index() + step)
+            } else {
+                NineStar::from_index(center.index() + 9 - step)
+            }
+        });
+        FlyingStars { center, forward, stars }
+    }
+
+    /// 某宫所飞到的星
+    pub fn star_at(&self, palace: NinePalace) -> NineStar {
+        self.stars[palace.index() - 1]
+    }
+
+    /// 某星所在的宫
+    pub fn palace_of(&self, star: NineStar) -> NinePalace {
+        let position = self.stars.iter().position(|s| *s == star).unwrap();
+        NinePalace::ALL[position]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attributes() {
+        assert_eq!(NineStar::WuHuang.wuxing(), WuXing::Earth);
+        assert_eq!(NineStar::JiuZi.color(), "紫");
+        assert_eq!(NineStar::BaBai.star_name(), "左辅");
+        assert_eq!("七赤".parse::<NineStar>(), Ok(NineStar::QiChi));
+        assert_eq!("4".parse::<NineStar>(), Ok(NineStar::SiLu));
+        assert_eq!(NineStar::JiuZi.next(), NineStar::YiBai);
+        for palace in NinePalace::ALL {
+            assert_eq!(NinePalace::FLIGHT[palace.flight_step()], palace);
+            if let Some(direction) = palace.direction() {
+                assert_eq!(NinePalace::from_direction(direction), palace);
+                assert_eq!(palace.opposite().direction(), Some(direction.opposite()));
+            }
+        }
+        assert_eq!(NinePalace::Kan.direction(), Some(Direction::North));
+        assert_eq!(NinePalace::Center.trigram(), None);
+    }
+
+    #[test]
+    fn test_flying() {
+        // 五黄入中顺飞即洛书本位
+        let chart = NineStar::WuHuang.fly(true);
+        for palace in NinePalace::ALL {
+            assert_eq!(chart.star_at(palace).home_palace(), palace);
+        }
+        // 八白入中顺飞：乾九、兑一、艮二、离三、坎四、坤五、震六、巽七
+        let chart = NineStar::BaBai.fly(true);
+        assert_eq!(chart.star_at(NinePalace::Qian), NineStar::JiuZi);
+        assert_eq!(chart.star_at(NinePalace::Kan), NineStar::SiLu);
+        assert_eq!(chart.palace_of(NineStar::SiLu), NinePalace::Kan);
+        // 逆飞：八白入中，乾七、兑六、艮五……巽九
+        let chart = NineStar::BaBai.fly(false);
+        assert_eq!(chart.star_at(NinePalace::Qian), NineStar::QiChi);
+        assert_eq!(chart.star_at(NinePalace::Gen), NineStar::WuHuang);
+        assert_eq!(chart.star_at(NinePalace::Xun), NineStar::JiuZi);
+    }
+
+    #[test]
+    fn test_ruling_stars() {
+        assert_eq!(NineStar::of_year(2024), NineStar::SanBi);
+        assert_eq!(NineStar::of_year(2026), NineStar::YiBai);
+        assert_eq!(NineStar::of_year(1984), NineStar::QiChi);
+        // 甲辰年（2024）寅月五黄、卯月四绿
+        assert_eq!(NineStar::of_month(EarthlyBranch::Chen, EarthlyBranch::Yin), NineStar::WuHuang);
+        assert_eq!(NineStar::of_month(EarthlyBranch::Chen, EarthlyBranch::Mao), NineStar::SiLu);
+        assert_eq!(NineStar::of_month(EarthlyBranch::Zi, EarthlyBranch::Yin), NineStar::BaBai);
+        assert_eq!(NineStar::of_month(EarthlyBranch::Si, EarthlyBranch::Yin), NineStar::ErHei);
+        // 子年丑月（十二月）之后接丑年寅月，月星连续
+        assert_eq!(NineStar::of_month(EarthlyBranch::Zi, EarthlyBranch::Chou), NineStar::LiuBai);
+        assert_eq!(NineStar::of_day(Dun::Yang, 0), NineStar::YiBai);
+        assert_eq!(NineStar::of_day(Dun::Yang, 60), NineStar::QiChi);
+        assert_eq!(NineStar::of_day(Dun::Yin, 0), NineStar::JiuZi);
+        assert_eq!(NineStar::of_day(Dun::Yin, 60), NineStar::SanBi);
+        assert_eq!(NineStar::of_hour(Dun::Yang, EarthlyBranch::Wu, EarthlyBranch::Zi), NineStar::YiBai);
+        assert_eq!(NineStar::of_hour(Dun::Yang, EarthlyBranch::Chou, EarthlyBranch::Zi), NineStar::SiLu);
+        assert_eq!(NineStar::of_hour(Dun::Yang, EarthlyBranch::Shen, EarthlyBranch::Chou), NineStar::BaBai);
+        assert_eq!(NineStar::of_hour(Dun::Yin, EarthlyBranch::You, EarthlyBranch::Zi), NineStar::JiuZi);
+        assert_eq!(NineStar::of_hour(Dun::Yin, EarthlyBranch::Wei, EarthlyBranch::Zi), NineStar::LiuBai);
+        assert_eq!(NineStar::of_hour(Dun::Yin, EarthlyBranch::Hai, EarthlyBranch::Zi), NineStar::SanBi);
+        assert_eq!(Dun::from_solar_longitude(280.0), Dun::Yang);
+        assert_eq!(Dun::from_solar_longitude(90.0), Dun::Yin);
+    }
+}
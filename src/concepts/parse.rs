@@ -0,0 +1,205 @@
+// src/concepts/parse.rs - 概念类型的文本解析
+// 支持简体、繁体中文，带或不带声调的拼音，以及从1开始的序号
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use alloc::string::{String, ToString};
+use serde::de::{self, IgnoredAny, MapAccess, Visitor};
+use crate::concepts::gan_zhi::{HeavenlyStem, EarthlyBranch};
+use crate::concepts::traits::ChineseName;
+
+/// 概念类型的解析错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// 无法识别的名称
+    UnknownName {
+        kind: &'static str,
+        input: String,
+    },
+    /// 序号超出范围
+    IndexOutOfRange {
+        kind: &'static str,
+        index: usize,
+        max: usize,
+    },
+    /// 天干地支阴阳不匹配，如"甲丑"
+    YinYangMismatch {
+        stem: HeavenlyStem,
+        branch: EarthlyBranch,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownName { kind, input } => {
+                write!(f, "无法识别的{}: \"{}\"", kind, input)
+            }
+            ParseError::IndexOutOfRange { kind, index, max } => {
+                write!(f, "{}序号 {} 超出范围 1..={}", kind, index, max)
+            }
+            ParseError::YinYangMismatch { stem, branch } => write!(
+                f,
+                "天干地支的阴阳属性不匹配: {}{}",
+                stem.chinese_name(),
+                branch.chinese_name()
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// 规范化输入：去除空白与分隔符、转为小写、去掉拼音声调（符号或数字）
+pub(crate) fn normalize(input: &str) -> String {
+    let has_letter = input.chars().any(|c| c.is_ascii_alphabetic());
+    input
+        .trim()
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_' | '\'' | '·'))
+        // 拼音后的数字声调，如 jia3
+        .filter(|c| !(has_letter && matches!(c, '1'..='5')))
+        .map(|c| match c {
+            'ā' | 'á' | 'ǎ' | 'à' => 'a',
+            'ē' | 'é' | 'ě' | 'è' => 'e',
+            'ī' | 'í' | 'ǐ' | 'ì' => 'i',
+            'ō' | 'ó' | 'ǒ' | 'ò' => 'o',
+            'ū' | 'ú' | 'ǔ' | 'ù' => 'u',
+            'ǖ' | 'ǘ' | 'ǚ' | 'ǜ' | 'ü' => 'v',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+/// 按别名或从1开始的序号在候选中查找
+/// 别名需为规范化后的形式（小写、无声调）
+pub(crate) fn parse_by_alias<T: Copy>(
+    input: &str,
+    kind: &'static str,
+    candidates: &[T],
+    aliases: impl Fn(&T) -> &'static [&'static str],
+) -> Result<T, ParseError> {
+    let normalized = normalize(input);
+    if let Ok(index) = normalized.parse::<usize>() {
+        return if (1..=candidates.len()).contains(&index) {
+            Ok(candidates[index - 1])
+        } else {
+            Err(ParseError::IndexOutOfRange { kind, index, max: candidates.len() })
+        };
+    }
+    candidates
+        .iter()
+        .find(|candidate| aliases(candidate).contains(&normalized.as_str()))
+        .copied()
+        .ok_or_else(|| ParseError::UnknownName { kind, input: input.to_string() })
+}
+
+/// 通用的反序列化访问器：接受字符串或整数序号，交由 FromStr 解析
+pub(crate) struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    pub(crate) fn new(expecting: &'static str) -> Self {
+        Self { expecting, marker: PhantomData }
+    }
+}
+
+impl<'de, T> Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr<Err = ParseError>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}的名称、拼音或序号", self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    /// 接受 Rich 表示的对象，按其中的 name 字段解析
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        let mut name: Option<String> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "name" {
+                name = Some(map.next_value()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+        name.parse().map_err(de::Error::custom)
+    }
+}
+
+/// 为实现了 FromStr 的概念类型实现 Deserialize
+macro_rules! impl_deserialize_from_str {
+    ($t:ty, $expecting:expr) => {
+        impl<'de> serde::Deserialize<'de> for $t {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_any(crate::concepts::parse::FromStrVisitor::new($expecting))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_deserialize_from_str;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{de::DeserializeOwned, Serialize};
+    use crate::concepts::bagua::Trigram;
+    use crate::concepts::wu_xing::WuXing;
+    use crate::concepts::yinyang::YinYang;
+    use crate::concepts::zhang_sheng::ZhangSheng;
+
+    fn assert_round_trip<T>(values: &[T])
+    where
+        T: Serialize + DeserializeOwned + FromStr<Err = ParseError> + PartialEq + fmt::Debug,
+    {
+        for (i, value) in values.iter().enumerate() {
+            let json = serde_json::to_string(value).unwrap();
+            assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), value);
+            assert_eq!(&(i + 1).to_string().parse::<T>().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_concept_round_trip() {
+        assert_round_trip(&WuXing::ALL);
+        assert_round_trip(&YinYang::ALL);
+        assert_round_trip(&Trigram::ALL);
+        assert_round_trip(&ZhangSheng::ALL);
+        assert_round_trip(&HeavenlyStem::ALL);
+        assert_round_trip(&EarthlyBranch::ALL);
+
+        for stage in ZhangSheng::ALL {
+            assert_eq!(stage.chinese_name().parse::<ZhangSheng>(), Ok(stage));
+        }
+        assert_eq!("陽".parse::<YinYang>(), Ok(YinYang::Yang));
+        assert_eq!("兌".parse::<Trigram>(), Ok(Trigram::Dui));
+        assert_eq!("臨官".parse::<ZhangSheng>(), Ok(ZhangSheng::LinGuan));
+        assert_eq!("Shuǐ".parse::<WuXing>(), Ok(WuXing::Water));
+        assert!(serde_json::from_str::<WuXing>("6").is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(" Gēng Wǔ "), "gengwu");
+        assert_eq!(normalize("jia3-zi3"), "jiazi");
+        assert_eq!(normalize("庚午"), "庚午");
+        assert_eq!(normalize("12"), "12");
+    }
+}
@@ -1,12 +1,575 @@
 // src/concepts/shen_sha.rs - 神煞模块
-// Placeholder for神煞相关实现
-use serde::Serialize;
+// 每个神煞都以数据描述：以哪一柱的干支起（anchor），再按查表找出目标干支所在的柱
+use core::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::concepts::gan_zhi::{GanZhi, HeavenlyStem, EarthlyBranch};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::concepts::traits::{ChineseName, Pinyin};
+use crate::json;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+/// 内置神煞枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ShenSha {
-    QingLong,  // 青龙
-    MingTang,  // 明堂
-    TianXiang, // 天厢
-    JuMen,     // 巨门
-    // 可以添加更多的神煞
-}
\ No newline at end of file
+    TianYiGuiRen, // 天乙贵人
+    WenChang,     // 文昌
+    YiMa,         // 驿马
+    TaoHua,       // 桃花（咸池）
+    HuaGai,       // 华盖
+    YangRen,      // 羊刃
+    LuShen,       // 禄神
+    JiangXing,    // 将星
+    JieSha,       // 劫煞
+    WangShen,     // 亡神
+    GuChen,       // 孤辰
+    GuaSu,        // 寡宿
+    TianDe,       // 天德
+    YueDe,        // 月德
+    HongLuan,     // 红鸾
+    TianXi,       // 天喜
+    KuiGang,      // 魁罡
+    ShiEDaBai,    // 十恶大败
+}
+
+impl ChineseName for ShenSha {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            ShenSha::TianYiGuiRen => "天乙贵人",
+            ShenSha::WenChang => "文昌",
+            ShenSha::YiMa => "驿马",
+            ShenSha::TaoHua => "桃花",
+            ShenSha::HuaGai => "华盖",
+            ShenSha::YangRen => "羊刃",
+            ShenSha::LuShen => "禄神",
+            ShenSha::JiangXing => "将星",
+            ShenSha::JieSha => "劫煞",
+            ShenSha::WangShen => "亡神",
+            ShenSha::GuChen => "孤辰",
+            ShenSha::GuaSu => "寡宿",
+            ShenSha::TianDe => "天德",
+            ShenSha::YueDe => "月德",
+            ShenSha::HongLuan => "红鸾",
+            ShenSha::TianXi => "天喜",
+            ShenSha::KuiGang => "魁罡",
+            ShenSha::ShiEDaBai => "十恶大败",
+        }
+    }
+}
+
+impl Pinyin for ShenSha {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            ShenSha::TianYiGuiRen => "tiān yǐ guì rén",
+            ShenSha::WenChang => "wén chāng",
+            ShenSha::YiMa => "yì mǎ",
+            ShenSha::TaoHua => "táo huā",
+            ShenSha::HuaGai => "huá gài",
+            ShenSha::YangRen => "yáng rèn",
+            ShenSha::LuShen => "lù shén",
+            ShenSha::JiangXing => "jiàng xīng",
+            ShenSha::JieSha => "jié shà",
+            ShenSha::WangShen => "wáng shén",
+            ShenSha::GuChen => "gū chén",
+            ShenSha::GuaSu => "guǎ sù",
+            ShenSha::TianDe => "tiān dé",
+            ShenSha::YueDe => "yuè dé",
+            ShenSha::HongLuan => "hóng luán",
+            ShenSha::TianXi => "tiān xǐ",
+            ShenSha::KuiGang => "kuí gāng",
+            ShenSha::ShiEDaBai => "shí è dà bài",
+        }
+    }
+}
+
+impl ShenSha {
+    /// 全部内置神煞
+    pub const ALL: [ShenSha; 18] = [
+        ShenSha::TianYiGuiRen, ShenSha::WenChang, ShenSha::YiMa, ShenSha::TaoHua,
+        ShenSha::HuaGai, ShenSha::YangRen, ShenSha::LuShen, ShenSha::JiangXing,
+        ShenSha::JieSha, ShenSha::WangShen, ShenSha::GuChen, ShenSha::GuaSu,
+        ShenSha::TianDe, ShenSha::YueDe, ShenSha::HongLuan, ShenSha::TianXi,
+        ShenSha::KuiGang, ShenSha::ShiEDaBai,
+    ];
+
+    // 可解析的别名：简繁中文与拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            ShenSha::TianYiGuiRen => &["天乙贵人", "天乙貴人", "tianyiguiren"],
+            ShenSha::WenChang => &["文昌", "wenchang"],
+            ShenSha::YiMa => &["驿马", "驛馬", "yima"],
+            ShenSha::TaoHua => &["桃花", "咸池", "taohua", "xianchi"],
+            ShenSha::HuaGai => &["华盖", "華蓋", "huagai"],
+            ShenSha::YangRen => &["羊刃", "yangren"],
+            ShenSha::LuShen => &["禄神", "祿神", "lushen"],
+            ShenSha::JiangXing => &["将星", "將星", "jiangxing"],
+            ShenSha::JieSha => &["劫煞", "jiesha"],
+            ShenSha::WangShen => &["亡神", "wangshen"],
+            ShenSha::GuChen => &["孤辰", "guchen"],
+            ShenSha::GuaSu => &["寡宿", "guasu"],
+            ShenSha::TianDe => &["天德", "tiande"],
+            ShenSha::YueDe => &["月德", "yuede"],
+            ShenSha::HongLuan => &["红鸾", "紅鸞", "hongluan"],
+            ShenSha::TianXi => &["天喜", "tianxi"],
+            ShenSha::KuiGang => &["魁罡", "kuigang"],
+            ShenSha::ShiEDaBai => &["十恶大败", "十惡大敗", "shiedabai"],
+        }
+    }
+
+    /// 获取该神煞的查法规则
+    pub fn rule(&self) -> ShenShaRule {
+        use Anchor::*;
+        // 三合局起法：申子辰、寅午戌、巳酉丑、亥卯未
+        let san_he = |targets: [&str; 4]| {
+            vec![
+                entry("申子辰", targets[0]),
+                entry("寅午戌", targets[1]),
+                entry("巳酉丑", targets[2]),
+                entry("亥卯未", targets[3]),
+            ]
+        };
+        let (anchors, table) = match self {
+            ShenSha::TianYiGuiRen => (vec![DayStem, YearStem], vec![
+                entry("甲戊庚", "丑未"), entry("乙己", "子申"), entry("丙丁", "亥酉"),
+                entry("壬癸", "巳卯"), entry("辛", "午寅"),
+            ]),
+            ShenSha::WenChang => (vec![DayStem, YearStem], vec![
+                entry("甲", "巳"), entry("乙", "午"), entry("丙戊", "申"), entry("丁己", "酉"),
+                entry("庚", "亥"), entry("辛", "子"), entry("壬", "寅"), entry("癸", "卯"),
+            ]),
+            ShenSha::YiMa => (vec![YearBranch, DayBranch], san_he(["寅", "申", "亥", "巳"])),
+            ShenSha::TaoHua => (vec![YearBranch, DayBranch], san_he(["酉", "卯", "午", "子"])),
+            ShenSha::HuaGai => (vec![YearBranch, DayBranch], san_he(["辰", "戌", "丑", "未"])),
+            ShenSha::JiangXing => (vec![YearBranch, DayBranch], san_he(["子", "午", "酉", "卯"])),
+            ShenSha::JieSha => (vec![YearBranch, DayBranch], san_he(["巳", "亥", "寅", "申"])),
+            ShenSha::WangShen => (vec![YearBranch, DayBranch], san_he(["亥", "巳", "申", "寅"])),
+            // 羊刃取日干帝旺之位（阳顺阴逆）
+            ShenSha::YangRen => (vec![DayStem], vec![
+                entry("甲", "卯"), entry("乙", "寅"), entry("丙戊", "午"), entry("丁己", "巳"),
+                entry("庚", "酉"), entry("辛", "申"), entry("壬", "子"), entry("癸", "亥"),
+            ]),
+            ShenSha::LuShen => (vec![DayStem], vec![
+                entry("甲", "寅"), entry("乙", "卯"), entry("丙戊", "巳"), entry("丁己", "午"),
+                entry("庚", "申"), entry("辛", "酉"), entry("壬", "亥"), entry("癸", "子"),
+            ]),
+            ShenSha::GuChen => (vec![YearBranch], vec![
+                entry("亥子丑", "寅"), entry("寅卯辰", "巳"), entry("巳午未", "申"), entry("申酉戌", "亥"),
+            ]),
+            ShenSha::GuaSu => (vec![YearBranch], vec![
+                entry("亥子丑", "戌"), entry("寅卯辰", "丑"), entry("巳午未", "辰"), entry("申酉戌", "未"),
+            ]),
+            ShenSha::TianDe => (vec![MonthBranch], vec![
+                entry("寅", "丁"), entry("卯", "申"), entry("辰", "壬"), entry("巳", "辛"),
+                entry("午", "亥"), entry("未", "甲"), entry("申", "癸"), entry("酉", "寅"),
+                entry("戌", "丙"), entry("亥", "乙"), entry("子", "巳"), entry("丑", "庚"),
+            ]),
+            ShenSha::YueDe => (vec![MonthBranch], vec![
+                entry("寅午戌", "丙"), entry("申子辰", "壬"), entry("亥卯未", "甲"), entry("巳酉丑", "庚"),
+            ]),
+            ShenSha::HongLuan => (vec![YearBranch], vec![
+                entry("子", "卯"), entry("丑", "寅"), entry("寅", "丑"), entry("卯", "子"),
+                entry("辰", "亥"), entry("巳", "戌"), entry("午", "酉"), entry("未", "申"),
+                entry("申", "未"), entry("酉", "午"), entry("戌", "巳"), entry("亥", "辰"),
+            ]),
+            ShenSha::TianXi => (vec![YearBranch], vec![
+                entry("子", "酉"), entry("丑", "申"), entry("寅", "未"), entry("卯", "午"),
+                entry("辰", "巳"), entry("巳", "辰"), entry("午", "卯"), entry("未", "寅"),
+                entry("申", "丑"), entry("酉", "子"), entry("戌", "亥"), entry("亥", "戌"),
+            ]),
+            ShenSha::KuiGang => (vec![DayPillar], vec![
+                pillar_entry(&["庚辰", "壬辰", "戊戌", "庚戌"]),
+            ]),
+            ShenSha::ShiEDaBai => (vec![DayPillar], vec![
+                pillar_entry(&["甲辰", "乙巳", "丙申", "丁亥", "戊戌", "己丑", "庚辰", "辛巳", "壬申", "癸亥"]),
+            ]),
+        };
+        ShenShaRule {
+            name: self.chinese_name().to_string(),
+            anchors,
+            table,
+        }
+    }
+}
+
+impl FromStr for ShenSha {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "神煞", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(ShenSha, "神煞");
+
+impl Serialize for ShenSha {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let index = Self::ALL.iter().position(|x| x == self).unwrap() + 1;
+        json::serialize_concept(self, index, serializer, |_| Ok(()))
+    }
+}
+
+/// 四柱位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Pillar {
+    /// 年柱
+    Year,
+    /// 月柱
+    Month,
+    /// 日柱
+    Day,
+    /// 时柱
+    Hour,
+}
+
+impl Pillar {
+    /// 四柱，按年月日时排列
+    pub const ALL: [Pillar; 4] = [Pillar::Year, Pillar::Month, Pillar::Day, Pillar::Hour];
+
+    fn position(&self) -> usize {
+        match self {
+            Pillar::Year => 0,
+            Pillar::Month => 1,
+            Pillar::Day => 2,
+            Pillar::Hour => 3,
+        }
+    }
+}
+
+impl ChineseName for Pillar {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Pillar::Year => "年柱",
+            Pillar::Month => "月柱",
+            Pillar::Day => "日柱",
+            Pillar::Hour => "时柱",
+        }
+    }
+}
+
+/// 神煞的起法依据
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Anchor {
+    /// 以年干起
+    YearStem,
+    /// 以年支起
+    YearBranch,
+    /// 以月干起
+    MonthStem,
+    /// 以月支起
+    MonthBranch,
+    /// 以日干起
+    DayStem,
+    /// 以日支起
+    DayBranch,
+    /// 以时干起
+    HourStem,
+    /// 以时支起
+    HourBranch,
+    /// 以年柱干支起
+    YearPillar,
+    /// 以月柱干支起
+    MonthPillar,
+    /// 以日柱干支起
+    DayPillar,
+    /// 以时柱干支起
+    HourPillar,
+}
+
+impl Anchor {
+    /// 起法所在的柱
+    pub fn pillar(&self) -> Pillar {
+        match self {
+            Anchor::YearStem | Anchor::YearBranch | Anchor::YearPillar => Pillar::Year,
+            Anchor::MonthStem | Anchor::MonthBranch | Anchor::MonthPillar => Pillar::Month,
+            Anchor::DayStem | Anchor::DayBranch | Anchor::DayPillar => Pillar::Day,
+            Anchor::HourStem | Anchor::HourBranch | Anchor::HourPillar => Pillar::Hour,
+        }
+    }
+
+    /// 从四柱中取出起法对应的干、支或干支
+    pub fn symbol(&self, pillars: &[GanZhi; 4]) -> Symbol {
+        let gan_zhi = pillars[self.pillar().position()];
+        match self {
+            Anchor::YearStem | Anchor::MonthStem | Anchor::DayStem | Anchor::HourStem => {
+                Symbol::Stem(gan_zhi.stem)
+            }
+            Anchor::YearBranch | Anchor::MonthBranch | Anchor::DayBranch | Anchor::HourBranch => {
+                Symbol::Branch(gan_zhi.branch)
+            }
+            _ => Symbol::Pillar(gan_zhi),
+        }
+    }
+}
+
+/// 神煞查表中使用的符号：天干、地支或整柱干支
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symbol {
+    Stem(HeavenlyStem),
+    Branch(EarthlyBranch),
+    Pillar(GanZhi),
+}
+
+impl Symbol {
+    /// 判断某一柱是否带有该符号
+    pub fn matches(&self, gan_zhi: &GanZhi) -> bool {
+        match self {
+            Symbol::Stem(stem) => gan_zhi.stem == *stem,
+            Symbol::Branch(branch) => gan_zhi.branch == *branch,
+            Symbol::Pillar(pillar) => gan_zhi == pillar,
+        }
+    }
+}
+
+impl ChineseName for Symbol {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Symbol::Stem(stem) => stem.chinese_name(),
+            Symbol::Branch(branch) => branch.chinese_name(),
+            Symbol::Pillar(gan_zhi) => gan_zhi.chinese_name(),
+        }
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = ParseError;
+
+    /// 依次按天干、地支、六十甲子解析，如"甲"、"子"、"甲子"；不接受序号
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || ParseError::UnknownName { kind: "干支符号", input: s.to_string() };
+        if parse::normalize(s).parse::<usize>().is_ok() {
+            return Err(unknown());
+        }
+        s.parse().map(Symbol::Stem)
+            .or_else(|_| s.parse().map(Symbol::Branch))
+            .or_else(|_| match s.parse() {
+                Ok(gan_zhi) => Ok(Symbol::Pillar(gan_zhi)),
+                Err(err @ ParseError::YinYangMismatch { .. }) => Err(err),
+                Err(_) => Err(unknown()),
+            })
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.chinese_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// 神煞查表的一行：起法符号命中 keys 之一时，带有 targets 之一的柱即带此神煞
+/// targets 为空时，表示起法所在的柱本身即带此神煞（如魁罡）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShenShaEntry {
+    pub keys: Vec<Symbol>,
+    #[serde(default)]
+    pub targets: Vec<Symbol>,
+}
+
+/// 以数据描述的神煞规则
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShenShaRule {
+    /// 神煞名称
+    pub name: String,
+    /// 起法依据，可有多个（如天乙贵人以日干、年干起）
+    pub anchors: Vec<Anchor>,
+    /// 查表
+    pub table: Vec<ShenShaEntry>,
+}
+
+impl ShenShaRule {
+    /// 查出四柱中带有此神煞的柱，按年月日时排列
+    pub fn find(&self, pillars: &[GanZhi; 4]) -> Vec<Pillar> {
+        let mut found = Vec::new();
+        for anchor in &self.anchors {
+            let symbol = anchor.symbol(pillars);
+            for entry in self.table.iter().filter(|entry| entry.keys.contains(&symbol)) {
+                if entry.targets.is_empty() {
+                    found.push(anchor.pillar());
+                    continue;
+                }
+                for pillar in Pillar::ALL {
+                    let gan_zhi = &pillars[pillar.position()];
+                    let hit = entry.targets.iter().any(|target| {
+                        // 以地支起、查地支的神煞不在起法本柱上查找
+                        let same_branch = matches!((symbol, target), (Symbol::Branch(_), Symbol::Branch(_)))
+                            && pillar == anchor.pillar();
+                        !same_branch && target.matches(gan_zhi)
+                    });
+                    if hit {
+                        found.push(pillar);
+                    }
+                }
+            }
+        }
+        found.sort();
+        found.dedup();
+        found
+    }
+}
+
+/// 神煞查找结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShenShaHit {
+    /// 神煞名称
+    pub name: String,
+    /// 带有此神煞的柱
+    pub pillars: Vec<Pillar>,
+}
+
+/// 神煞规则表
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShenShaCatalog {
+    rules: Vec<ShenShaRule>,
+}
+
+impl Default for ShenShaCatalog {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl ShenShaCatalog {
+    /// 创建空的规则表
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// 创建包含全部内置神煞的规则表
+    pub fn builtin() -> Self {
+        Self {
+            rules: ShenSha::ALL.iter().map(ShenSha::rule).collect(),
+        }
+    }
+
+    /// 获取全部规则
+    pub fn rules(&self) -> &[ShenShaRule] {
+        &self.rules
+    }
+
+    /// 添加规则，同名规则会被替换
+    pub fn add_rule(&mut self, rule: ShenShaRule) {
+        match self.rules.iter_mut().find(|r| r.name == rule.name) {
+            Some(existing) => *existing = rule,
+            None => self.rules.push(rule),
+        }
+    }
+
+    /// 从 JSON 数组加载额外的神煞规则，返回加载的条数
+    pub fn load_json(&mut self, json: &str) -> Result<usize, serde_json::Error> {
+        let rules: Vec<ShenShaRule> = serde_json::from_str(json)?;
+        let count = rules.len();
+        for rule in rules {
+            self.add_rule(rule);
+        }
+        Ok(count)
+    }
+
+    /// 查出四柱所带的全部神煞，未命中的神煞不列出
+    pub fn evaluate(&self, pillars: &[GanZhi; 4]) -> Vec<ShenShaHit> {
+        self.rules
+            .iter()
+            .filter_map(|rule| {
+                let pillars = rule.find(pillars);
+                (!pillars.is_empty()).then(|| ShenShaHit {
+                    name: rule.name.clone(),
+                    pillars,
+                })
+            })
+            .collect()
+    }
+}
+
+// 以单字干支构造查表行，如 entry("申子辰", "寅")
+fn entry(keys: &str, targets: &str) -> ShenShaEntry {
+    let parse = |s: &str| -> Vec<Symbol> {
+        s.chars()
+            .map(|c| c.encode_utf8(&mut [0; 4]).parse().unwrap())
+            .collect()
+    };
+    ShenShaEntry {
+        keys: parse(keys),
+        targets: parse(targets),
+    }
+}
+
+// 以整柱干支构造查表行，命中时起法本柱即带此神煞
+fn pillar_entry(keys: &[&str]) -> ShenShaEntry {
+    ShenShaEntry {
+        keys: keys.iter().map(|key| key.parse().unwrap()).collect(),
+        targets: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gz(name: &str) -> GanZhi {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn test_builtin_catalog() {
+        // 庚午年 辛巳月 庚辰日 辛巳时
+        let pillars = [gz("庚午"), gz("辛巳"), gz("庚辰"), gz("辛巳")];
+        let hits = ShenShaCatalog::builtin().evaluate(&pillars);
+        let find = |name: &str| hits.iter().find(|hit| hit.name == name).map(|hit| hit.pillars.clone());
+
+        assert_eq!(find("魁罡"), Some(vec![Pillar::Day]));
+        assert_eq!(find("十恶大败"), Some(vec![Pillar::Day]));
+        // 午年驿马在申，四柱无申
+        assert_eq!(find("驿马"), None);
+        // 辰日（申子辰）劫煞在巳
+        assert_eq!(find("劫煞"), Some(vec![Pillar::Month, Pillar::Hour]));
+        // 庚干文昌在亥
+        assert_eq!(find("文昌"), None);
+        // 巳月天德在辛
+        assert_eq!(find("天德"), Some(vec![Pillar::Month, Pillar::Hour]));
+    }
+
+    #[test]
+    fn test_load_json() {
+        let mut catalog = ShenShaCatalog::new();
+        let count = catalog.load_json(r#"[
+            {"name": "金舆", "anchors": ["DayStem"], "table": [{"keys": ["庚"], "targets": ["亥"]}]},
+            {"name": "日德", "anchors": ["DayPillar"], "table": [{"keys": ["庚辰", "甲寅"]}]}
+        ]"#).unwrap();
+        assert_eq!(count, 2);
+
+        let pillars = [gz("辛亥"), gz("辛巳"), gz("庚辰"), gz("辛巳")];
+        let hits = catalog.evaluate(&pillars);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].pillars, vec![Pillar::Year]);
+        assert_eq!(hits[1].pillars, vec![Pillar::Day]);
+
+        assert!(catalog.load_json(r#"[{"name": "x", "anchors": ["DayStem"], "table": [{"keys": ["甲丑"]}]}]"#).is_err());
+    }
+
+    #[test]
+    fn test_shen_sha_round_trip() {
+        for shen_sha in ShenSha::ALL {
+            let json = serde_json::to_string(&shen_sha).unwrap();
+            assert_eq!(serde_json::from_str::<ShenSha>(&json).unwrap(), shen_sha);
+            assert_eq!(shen_sha.chinese_name().parse::<ShenSha>(), Ok(shen_sha));
+        }
+        assert_eq!("咸池".parse::<ShenSha>(), Ok(ShenSha::TaoHua));
+        assert_eq!("驛馬".parse::<ShenSha>(), Ok(ShenSha::YiMa));
+    }
+
+    #[test]
+    fn test_rule_round_trip() {
+        for shen_sha in ShenSha::ALL {
+            let rule = shen_sha.rule();
+            let json = serde_json::to_string(&rule).unwrap();
+            let parsed: ShenShaRule = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, rule);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::concepts::yinyang::YinYang;
 use crate::concepts::wu_xing::WuXing;
 
@@ -36,6 +37,12 @@ pub trait ChineseName {
     fn chinese_name(&self) -> &'static str;
 }
 
+/// 用于获取事物的带声调拼音
+pub trait Pinyin {
+    /// 获取拼音，多字之间以空格分隔
+    fn pinyin(&self) -> &'static str;
+}
+
 /// 用于获取事物在序列中的索引，索引从1开始
 pub trait Index {
     /// 从索引创建实例
@@ -1,5 +1,8 @@
-use serde::Serialize;
-use crate::concepts::traits::ChineseName;
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use crate::concepts::traits::{ChineseName, Pinyin};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::json;
 
 /// 五行关系枚举
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
@@ -16,8 +19,35 @@ pub enum WuXingRelation {
     He,
 }
 
+/// 五行的旺相休囚死，表示在某一时令下的强弱
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WangXiang {
+    /// 旺：与当令者相同
+    Wang,
+    /// 相：当令者所生
+    Xiang,
+    /// 休：生当令者
+    Xiu,
+    /// 囚：克当令者
+    Qiu,
+    /// 死：被当令者所克
+    Si,
+}
+
+impl ChineseName for WangXiang {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            WangXiang::Wang => "旺",
+            WangXiang::Xiang => "相",
+            WangXiang::Xiu => "休",
+            WangXiang::Qiu => "囚",
+            WangXiang::Si => "死",
+        }
+    }
+}
+
 /// 五行枚举，表示事物的五行属性
-#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WuXing {
     /// 木
     Wood,
@@ -31,8 +61,8 @@ pub enum WuXing {
     Water,
 }
 
-impl std::fmt::Display for WuXing {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for WuXing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             WuXing::Wood => write!(f, "木"),
             WuXing::Fire => write!(f, "火"),
@@ -54,3 +84,74 @@ impl ChineseName for WuXing {
         }
     }
 }
+
+impl Pinyin for WuXing {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            WuXing::Wood => "mù",
+            WuXing::Fire => "huǒ",
+            WuXing::Earth => "tǔ",
+            WuXing::Metal => "jīn",
+            WuXing::Water => "shuǐ",
+        }
+    }
+}
+
+impl WuXing {
+    /// 五行，按木火土金水排列
+    pub const ALL: [WuXing; 5] = [WuXing::Wood, WuXing::Fire, WuXing::Earth, WuXing::Metal, WuXing::Water];
+
+    /// 我生者：木生火、火生土、土生金、金生水、水生木
+    pub fn generates(&self) -> WuXing {
+        Self::ALL[(*self as usize + 1) % 5]
+    }
+
+    /// 我克者：木克土、土克水、水克火、火克金、金克木
+    pub fn overcomes(&self) -> WuXing {
+        Self::ALL[(*self as usize + 2) % 5]
+    }
+
+    /// 在ruling当令时的旺相休囚死
+    /// 月令的当令五行即月支五行：寅卯木、巳午火、申酉金、亥子水、辰戌丑未土
+    pub fn wang_xiang(&self, ruling: WuXing) -> WangXiang {
+        if *self == ruling {
+            WangXiang::Wang
+        } else if ruling.generates() == *self {
+            WangXiang::Xiang
+        } else if self.generates() == ruling {
+            WangXiang::Xiu
+        } else if self.overcomes() == ruling {
+            WangXiang::Qiu
+        } else {
+            WangXiang::Si
+        }
+    }
+
+    // 可解析的别名：中文、拼音与英文
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            WuXing::Wood => &["木", "mu", "wood"],
+            WuXing::Fire => &["火", "huo", "fire"],
+            WuXing::Earth => &["土", "tu", "earth"],
+            WuXing::Metal => &["金", "jin", "metal"],
+            WuXing::Water => &["水", "shui", "water"],
+        }
+    }
+}
+
+impl FromStr for WuXing {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "五行", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(WuXing, "五行");
+
+impl Serialize for WuXing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let index = Self::ALL.iter().position(|x| x == self).unwrap() + 1;
+        json::serialize_concept(self, index, serializer, |_| Ok(()))
+    }
+}
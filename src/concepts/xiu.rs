@@ -0,0 +1,585 @@
+// src/concepts/xiu.rs - 二十八宿模块
+// 二十八宿的四象、七曜、禽星与五行，古度宿度表，以及按各宿起点求入宿度
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+use crate::concepts::direction::Direction;
+use crate::concepts::wu_xing::WuXing;
+use crate::concepts::traits::{ChineseName, Pinyin, Index, Iter, WuXingTrait};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::json;
+
+/// 二十八宿，自角宿起按东方七宿、北方七宿、西方七宿、南方七宿排列
+/// 因宿名拼音多有重复（尾、危、胃；毕、壁），以禽星全名命名
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XiuMansion {
+    /// 角木蛟
+    JiaoMuJiao,
+    /// 亢金龙
+    KangJinLong,
+    /// 氐土貉
+    DiTuHe,
+    /// 房日兔
+    FangRiTu,
+    /// 心月狐
+    XinYueHu,
+    /// 尾火虎
+    WeiHuoHu,
+    /// 箕水豹
+    JiShuiBao,
+    /// 斗木獬
+    DouMuXie,
+    /// 牛金牛
+    NiuJinNiu,
+    /// 女土蝠
+    NvTuFu,
+    /// 虚日鼠
+    XuRiShu,
+    /// 危月燕
+    WeiYueYan,
+    /// 室火猪
+    ShiHuoZhu,
+    /// 壁水㺄
+    BiShuiYu,
+    /// 奎木狼
+    KuiMuLang,
+    /// 娄金狗
+    LouJinGou,
+    /// 胃土雉
+    WeiTuZhi,
+    /// 昴日鸡
+    MaoRiJi,
+    /// 毕月乌
+    BiYueWu,
+    /// 觜火猴
+    ZiHuoHou,
+    /// 参水猿
+    ShenShuiYuan,
+    /// 井木犴
+    JingMuAn,
+    /// 鬼金羊
+    GuiJinYang,
+    /// 柳土獐
+    LiuTuZhang,
+    /// 星日马
+    XingRiMa,
+    /// 张月鹿
+    ZhangYueLu,
+    /// 翼火蛇
+    YiHuoShe,
+    /// 轸水蚓
+    ZhenShuiYin,
+}
+
+impl ChineseName for XiuMansion {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            XiuMansion::JiaoMuJiao => "角",
+            XiuMansion::KangJinLong => "亢",
+            XiuMansion::DiTuHe => "氐",
+            XiuMansion::FangRiTu => "房",
+            XiuMansion::XinYueHu => "心",
+            XiuMansion::WeiHuoHu => "尾",
+            XiuMansion::JiShuiBao => "箕",
+            XiuMansion::DouMuXie => "斗",
+            XiuMansion::NiuJinNiu => "牛",
+            XiuMansion::NvTuFu => "女",
+            XiuMansion::XuRiShu => "虚",
+            XiuMansion::WeiYueYan => "危",
+            XiuMansion::ShiHuoZhu => "室",
+            XiuMansion::BiShuiYu => "壁",
+            XiuMansion::KuiMuLang => "奎",
+            XiuMansion::LouJinGou => "娄",
+            XiuMansion::WeiTuZhi => "胃",
+            XiuMansion::MaoRiJi => "昴",
+            XiuMansion::BiYueWu => "毕",
+            XiuMansion::ZiHuoHou => "觜",
+            XiuMansion::ShenShuiYuan => "参",
+            XiuMansion::JingMuAn => "井",
+            XiuMansion::GuiJinYang => "鬼",
+            XiuMansion::LiuTuZhang => "柳",
+            XiuMansion::XingRiMa => "星",
+            XiuMansion::ZhangYueLu => "张",
+            XiuMansion::YiHuoShe => "翼",
+            XiuMansion::ZhenShuiYin => "轸",
+        }
+    }
+}
+
+impl Pinyin for XiuMansion {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            XiuMansion::JiaoMuJiao => "jiǎo",
+            XiuMansion::KangJinLong => "kàng",
+            XiuMansion::DiTuHe => "dǐ",
+            XiuMansion::FangRiTu => "fáng",
+            XiuMansion::XinYueHu => "xīn",
+            XiuMansion::WeiHuoHu => "wěi",
+            XiuMansion::JiShuiBao => "jī",
+            XiuMansion::DouMuXie => "dǒu",
+            XiuMansion::NiuJinNiu => "niú",
+            XiuMansion::NvTuFu => "nǚ",
+            XiuMansion::XuRiShu => "xū",
+            XiuMansion::WeiYueYan => "wēi",
+            XiuMansion::ShiHuoZhu => "shì",
+            XiuMansion::BiShuiYu => "bì",
+            XiuMansion::KuiMuLang => "kuí",
+            XiuMansion::LouJinGou => "lóu",
+            XiuMansion::WeiTuZhi => "wèi",
+            XiuMansion::MaoRiJi => "mǎo",
+            XiuMansion::BiYueWu => "bì",
+            XiuMansion::ZiHuoHou => "zī",
+            XiuMansion::ShenShuiYuan => "shēn",
+            XiuMansion::JingMuAn => "jǐng",
+            XiuMansion::GuiJinYang => "guǐ",
+            XiuMansion::LiuTuZhang => "liǔ",
+            XiuMansion::XingRiMa => "xīng",
+            XiuMansion::ZhangYueLu => "zhāng",
+            XiuMansion::YiHuoShe => "yì",
+            XiuMansion::ZhenShuiYin => "zhěn",
+        }
+    }
+}
+
+impl Index for XiuMansion {
+    // 序号按28循环，0视为28（轸宿）
+    fn from_index(index: usize) -> Self {
+        Self::ALL[(index + 27) % 28]
+    }
+
+    fn index(&self) -> usize {
+        *self as usize + 1
+    }
+}
+
+impl Iter for XiuMansion {
+    type Item = Self;
+
+    /// 下一宿，轸宿之后为角宿
+    fn next(&self) -> Self::Item {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// 上一宿，角宿之前为轸宿
+    fn prev(&self) -> Self::Item {
+        Self::from_index(self.index() + 27)
+    }
+}
+
+impl WuXingTrait for XiuMansion {
+    /// 五行从所值七曜
+    fn wuxing(&self) -> WuXing {
+        self.qi_yao().wuxing()
+    }
+}
+
+impl XiuMansion {
+    /// 二十八宿，自角宿至轸宿
+    pub const ALL: [XiuMansion; 28] = [
+        XiuMansion::JiaoMuJiao, XiuMansion::KangJinLong, XiuMansion::DiTuHe, XiuMansion::FangRiTu,
+        XiuMansion::XinYueHu, XiuMansion::WeiHuoHu, XiuMansion::JiShuiBao,
+        XiuMansion::DouMuXie, XiuMansion::NiuJinNiu, XiuMansion::NvTuFu, XiuMansion::XuRiShu,
+        XiuMansion::WeiYueYan, XiuMansion::ShiHuoZhu, XiuMansion::BiShuiYu,
+        XiuMansion::KuiMuLang, XiuMansion::LouJinGou, XiuMansion::WeiTuZhi, XiuMansion::MaoRiJi,
+        XiuMansion::BiYueWu, XiuMansion::ZiHuoHou, XiuMansion::ShenShuiYuan,
+        XiuMansion::JingMuAn, XiuMansion::GuiJinYang, XiuMansion::LiuTuZhang, XiuMansion::XingRiMa,
+        XiuMansion::ZhangYueLu, XiuMansion::YiHuoShe, XiuMansion::ZhenShuiYin,
+    ];
+
+    /// 所属四象，每象七宿
+    pub fn xiang(&self) -> SiXiang {
+        SiXiang::ALL[(self.index() - 1) / 7]
+    }
+
+    /// 所值七曜，每象依木、金、土、日、月、火、水轮值
+    pub fn qi_yao(&self) -> QiYao {
+        const ORDER: [QiYao; 7] = [
+            QiYao::Wood, QiYao::Metal, QiYao::Earth, QiYao::Sun, QiYao::Moon, QiYao::Fire, QiYao::Water,
+        ];
+        ORDER[(self.index() - 1) % 7]
+    }
+
+    /// 禽星动物：蛟、龙、貉、兔、狐、虎、豹……
+    pub fn animal(&self) -> &'static str {
+        match self {
+            XiuMansion::JiaoMuJiao => "蛟",
+            XiuMansion::KangJinLong => "龙",
+            XiuMansion::DiTuHe => "貉",
+            XiuMansion::FangRiTu => "兔",
+            XiuMansion::XinYueHu => "狐",
+            XiuMansion::WeiHuoHu => "虎",
+            XiuMansion::JiShuiBao => "豹",
+            XiuMansion::DouMuXie => "獬",
+            XiuMansion::NiuJinNiu => "牛",
+            XiuMansion::NvTuFu => "蝠",
+            XiuMansion::XuRiShu => "鼠",
+            XiuMansion::WeiYueYan => "燕",
+            XiuMansion::ShiHuoZhu => "猪",
+            XiuMansion::BiShuiYu => "㺄",
+            XiuMansion::KuiMuLang => "狼",
+            XiuMansion::LouJinGou => "狗",
+            XiuMansion::WeiTuZhi => "雉",
+            XiuMansion::MaoRiJi => "鸡",
+            XiuMansion::BiYueWu => "乌",
+            XiuMansion::ZiHuoHou => "猴",
+            XiuMansion::ShenShuiYuan => "猿",
+            XiuMansion::JingMuAn => "犴",
+            XiuMansion::GuiJinYang => "羊",
+            XiuMansion::LiuTuZhang => "獐",
+            XiuMansion::XingRiMa => "马",
+            XiuMansion::ZhangYueLu => "鹿",
+            XiuMansion::YiHuoShe => "蛇",
+            XiuMansion::ZhenShuiYin => "蚓",
+        }
+    }
+
+    /// 距星在 Swiss Ephemeris 恒星表（ephe/sefstars.txt）中的拜耳名，可直接用于 swe_fixstar2
+    /// 奎宿取 ζ And，觜宿取 λ Ori，参宿取 ζ Ori
+    pub fn determinative_star(&self) -> &'static str {
+        match self {
+            XiuMansion::JiaoMuJiao => ",alVir",
+            XiuMansion::KangJinLong => ",kaVir",
+            XiuMansion::DiTuHe => ",al-2Lib",
+            XiuMansion::FangRiTu => ",piSco",
+            XiuMansion::XinYueHu => ",siSco",
+            XiuMansion::WeiHuoHu => ",mu-1Sco",
+            XiuMansion::JiShuiBao => ",gaSgr",
+            XiuMansion::DouMuXie => ",phSgr",
+            XiuMansion::NiuJinNiu => ",beCap",
+            XiuMansion::NvTuFu => ",epAqr",
+            XiuMansion::XuRiShu => ",beAqr",
+            XiuMansion::WeiYueYan => ",alAqr",
+            XiuMansion::ShiHuoZhu => ",alPeg",
+            XiuMansion::BiShuiYu => ",gaPeg",
+            XiuMansion::KuiMuLang => ",zeAnd",
+            XiuMansion::LouJinGou => ",beAri",
+            XiuMansion::WeiTuZhi => ",35Ari",
+            XiuMansion::MaoRiJi => ",17Tau",
+            XiuMansion::BiYueWu => ",epTau",
+            XiuMansion::ZiHuoHou => ",laOri",
+            XiuMansion::ShenShuiYuan => ",zeOri",
+            XiuMansion::JingMuAn => ",muGem",
+            XiuMansion::GuiJinYang => ",thCnc",
+            XiuMansion::LiuTuZhang => ",deHya",
+            XiuMansion::XingRiMa => ",alHya",
+            XiuMansion::ZhangYueLu => ",up-1Hya",
+            XiuMansion::YiHuoShe => ",alCrt",
+            XiuMansion::ZhenShuiYin => ",gaCrv",
+        }
+    }
+
+    /// 古度宿度（周天365¼度）：赤道宿度从汉代石氏，黄道宿度从《后汉书·律历志》
+    pub fn ancient_width(&self, frame: MansionFrame) -> f64 {
+        const EQUATORIAL: [f64; 28] = [
+            12.0, 9.0, 15.0, 5.0, 5.0, 18.0, 11.0,
+            26.25, 8.0, 12.0, 10.0, 17.0, 16.0, 9.0,
+            16.0, 12.0, 14.0, 11.0, 16.0, 2.0, 9.0,
+            33.0, 4.0, 15.0, 7.0, 18.0, 18.0, 17.0,
+        ];
+        const ECLIPTIC: [f64; 28] = [
+            13.0, 10.0, 16.0, 5.0, 5.0, 18.0, 10.0,
+            24.25, 7.0, 11.0, 10.0, 16.0, 18.0, 10.0,
+            17.0, 12.0, 15.0, 12.0, 16.0, 3.0, 8.0,
+            30.0, 4.0, 14.0, 7.0, 17.0, 19.0, 18.0,
+        ];
+        let table = match frame {
+            MansionFrame::Ecliptic => &ECLIPTIC,
+            MansionFrame::Equatorial => &EQUATORIAL,
+        };
+        table[self.index() - 1]
+    }
+
+    // 可解析的别名：简繁中文、禽星全名与拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            XiuMansion::JiaoMuJiao => &["角", "角宿", "角木蛟", "jiao", "jiaomujiao"],
+            XiuMansion::KangJinLong => &["亢", "亢宿", "亢金龙", "亢金龍", "kang", "kangjinlong"],
+            XiuMansion::DiTuHe => &["氐", "氐宿", "氐土貉", "di", "dituhe"],
+            XiuMansion::FangRiTu => &["房", "房宿", "房日兔", "fang", "fangritu"],
+            XiuMansion::XinYueHu => &["心", "心宿", "心月狐", "xin", "xinyuehu"],
+            XiuMansion::WeiHuoHu => &["尾", "尾宿", "尾火虎", "weihuohu"],
+            XiuMansion::JiShuiBao => &["箕", "箕宿", "箕水豹", "ji", "jishuibao"],
+            XiuMansion::DouMuXie => &["斗", "斗宿", "斗木獬", "dou", "doumuxie"],
+            XiuMansion::NiuJinNiu => &["牛", "牛宿", "牛金牛", "niu", "niujinniu"],
+            XiuMansion::NvTuFu => &["女", "女宿", "女土蝠", "nv", "nvtufu"],
+            XiuMansion::XuRiShu => &["虚", "虛", "虚宿", "虛宿", "虚日鼠", "虛日鼠", "xu", "xurishu"],
+            XiuMansion::WeiYueYan => &["危", "危宿", "危月燕", "weiyueyan"],
+            XiuMansion::ShiHuoZhu => &["室", "室宿", "室火猪", "室火豬", "shi", "shihuozhu"],
+            XiuMansion::BiShuiYu => &["壁", "壁宿", "壁水㺄", "壁水貐", "bishuiyu"],
+            XiuMansion::KuiMuLang => &["奎", "奎宿", "奎木狼", "kui", "kuimulang"],
+            XiuMansion::LouJinGou => &["娄", "婁", "娄宿", "婁宿", "娄金狗", "婁金狗", "lou", "loujingou"],
+            XiuMansion::WeiTuZhi => &["胃", "胃宿", "胃土雉", "weituzhi"],
+            XiuMansion::MaoRiJi => &["昴", "昴宿", "昴日鸡", "昴日雞", "mao", "maoriji"],
+            XiuMansion::BiYueWu => &["毕", "畢", "毕宿", "畢宿", "毕月乌", "畢月烏", "biyuewu"],
+            XiuMansion::ZiHuoHou => &["觜", "觜宿", "觜火猴", "zi", "zihuohou"],
+            XiuMansion::ShenShuiYuan => &["参", "參", "参宿", "參宿", "参水猿", "參水猿", "shen", "shenshuiyuan"],
+            XiuMansion::JingMuAn => &["井", "井宿", "井木犴", "jing", "jingmuan"],
+            XiuMansion::GuiJinYang => &["鬼", "鬼宿", "鬼金羊", "gui", "guijinyang"],
+            XiuMansion::LiuTuZhang => &["柳", "柳宿", "柳土獐", "liu", "liutuzhang"],
+            XiuMansion::XingRiMa => &["星", "星宿", "星日马", "星日馬", "xing", "xingrima"],
+            XiuMansion::ZhangYueLu => &["张", "張", "张宿", "張宿", "张月鹿", "張月鹿", "zhang", "zhangyuelu"],
+            XiuMansion::YiHuoShe => &["翼", "翼宿", "翼火蛇", "yi", "yihuoshe"],
+            XiuMansion::ZhenShuiYin => &["轸", "軫", "轸宿", "軫宿", "轸水蚓", "軫水蚓", "zhen", "zhenshuiyin"],
+        }
+    }
+}
+
+impl FromStr for XiuMansion {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "二十八宿", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(XiuMansion, "二十八宿");
+
+impl Serialize for XiuMansion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |map| {
+            map.serialize_entry("xiang", &self.xiang())?;
+            map.serialize_entry("qi_yao", &self.qi_yao())?;
+            map.serialize_entry("animal", self.animal())?;
+            map.serialize_entry("element", &self.wuxing())
+        })
+    }
+}
+
+/// 四象，各统七宿
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SiXiang {
+    /// 东方青龙
+    QingLong,
+    /// 北方玄武
+    XuanWu,
+    /// 西方白虎
+    BaiHu,
+    /// 南方朱雀
+    ZhuQue,
+}
+
+impl ChineseName for SiXiang {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            SiXiang::QingLong => "青龙",
+            SiXiang::XuanWu => "玄武",
+            SiXiang::BaiHu => "白虎",
+            SiXiang::ZhuQue => "朱雀",
+        }
+    }
+}
+
+impl WuXingTrait for SiXiang {
+    fn wuxing(&self) -> WuXing {
+        match self {
+            SiXiang::QingLong => WuXing::Wood,
+            SiXiang::XuanWu => WuXing::Water,
+            SiXiang::BaiHu => WuXing::Metal,
+            SiXiang::ZhuQue => WuXing::Fire,
+        }
+    }
+}
+
+impl SiXiang {
+    /// 四象，按东、北、西、南排列，与二十八宿次序一致
+    pub const ALL: [SiXiang; 4] = [SiXiang::QingLong, SiXiang::XuanWu, SiXiang::BaiHu, SiXiang::ZhuQue];
+
+    /// 所主方位
+    pub fn direction(&self) -> Direction {
+        match self {
+            SiXiang::QingLong => Direction::East,
+            SiXiang::XuanWu => Direction::North,
+            SiXiang::BaiHu => Direction::West,
+            SiXiang::ZhuQue => Direction::South,
+        }
+    }
+}
+
+/// 七曜：日、月与火水木金土五星
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum QiYao {
+    /// 日
+    Sun,
+    /// 月
+    Moon,
+    /// 火
+    Fire,
+    /// 水
+    Water,
+    /// 木
+    Wood,
+    /// 金
+    Metal,
+    /// 土
+    Earth,
+}
+
+impl ChineseName for QiYao {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            QiYao::Sun => "日",
+            QiYao::Moon => "月",
+            QiYao::Fire => "火",
+            QiYao::Water => "水",
+            QiYao::Wood => "木",
+            QiYao::Metal => "金",
+            QiYao::Earth => "土",
+        }
+    }
+}
+
+impl WuXingTrait for QiYao {
+    /// 日为火之精、月为水之精
+    fn wuxing(&self) -> WuXing {
+        match self {
+            QiYao::Sun | QiYao::Fire => WuXing::Fire,
+            QiYao::Moon | QiYao::Water => WuXing::Water,
+            QiYao::Wood => WuXing::Wood,
+            QiYao::Metal => WuXing::Metal,
+            QiYao::Earth => WuXing::Earth,
+        }
+    }
+}
+
+/// 宿度所用的坐标：黄道按黄经，赤道按赤经
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum MansionFrame {
+    /// 黄道宿度
+    #[default]
+    Ecliptic,
+    /// 赤道宿度
+    Equatorial,
+}
+
+impl ChineseName for MansionFrame {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            MansionFrame::Ecliptic => "黄道",
+            MansionFrame::Equatorial => "赤道",
+        }
+    }
+}
+
+/// 古度周天度数
+pub const ANCIENT_CIRCLE: f64 = 365.25;
+
+// 归一到 0..360
+fn normalize(degree: f64) -> f64 {
+    (degree % 360.0 + 360.0) % 360.0
+}
+
+/// 二十八宿宿度表：各宿起点（距星所在）的经度
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MansionTable {
+    /// 黄道或赤道
+    pub frame: MansionFrame,
+    /// 自角宿至轸宿各宿起点的经度（度）
+    pub starts: [f64; 28],
+}
+
+/// 入宿：所在之宿与入宿度
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MansionPlacement {
+    /// 所在之宿
+    pub mansion: XiuMansion,
+    /// 入宿度，即距该宿起点的度数
+    pub degree: f64,
+}
+
+impl MansionTable {
+    /// 由各宿起点经度建表，如各宿距星在某一历元的黄经或赤经
+    pub fn from_starts(frame: MansionFrame, starts: [f64; 28]) -> Self {
+        MansionTable { frame, starts: starts.map(normalize) }
+    }
+
+    /// 以角宿起点为准，按古度宿度依次累加建表（古度按周天365¼度折算为度）
+    pub fn ancient(frame: MansionFrame, jiao_start: f64) -> Self {
+        let mut starts = [0.0; 28];
+        let mut start = jiao_start;
+        for (slot, mansion) in starts.iter_mut().zip(XiuMansion::ALL) {
+            *slot = normalize(start);
+            start += mansion.ancient_width(frame) * 360.0 / ANCIENT_CIRCLE;
+        }
+        MansionTable { frame, starts }
+    }
+
+    /// 某宿起点的经度
+    pub fn start(&self, mansion: XiuMansion) -> f64 {
+        self.starts[mansion.index() - 1]
+    }
+
+    /// 某宿的宽度：自本宿起点至其后最近一宿起点的度数
+    /// 距星先后与宿序不一致时（如觜、参），按实际先后量取
+    pub fn width(&self, mansion: XiuMansion) -> f64 {
+        let start = self.start(mansion);
+        self.starts
+            .iter()
+            .map(|other| normalize(other - start))
+            .filter(|gap| *gap > 0.0)
+            .fold(360.0, f64::min)
+    }
+
+    /// 经度所入之宿：起点在该经度之前且最近的一宿
+    pub fn locate(&self, longitude: f64) -> MansionPlacement {
+        let longitude = normalize(longitude);
+        let mut best = MansionPlacement { mansion: XiuMansion::JiaoMuJiao, degree: f64::INFINITY };
+        for mansion in XiuMansion::ALL {
+            let degree = normalize(longitude - self.start(mansion));
+            if degree < best.degree {
+                best = MansionPlacement { mansion, degree };
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attributes() {
+        let mansion = XiuMansion::FangRiTu;
+        assert_eq!(mansion.chinese_name(), "房");
+        assert_eq!(mansion.xiang(), SiXiang::QingLong);
+        assert_eq!(mansion.qi_yao(), QiYao::Sun);
+        assert_eq!(mansion.animal(), "兔");
+        assert_eq!(XiuMansion::GuiJinYang.qi_yao(), QiYao::Metal);
+        assert_eq!(XiuMansion::GuiJinYang.xiang(), SiXiang::ZhuQue);
+        assert_eq!(XiuMansion::ShenShuiYuan.wuxing(), WuXing::Water);
+        assert_eq!(XiuMansion::ZhenShuiYin.next(), XiuMansion::JiaoMuJiao);
+        assert_eq!("危".parse::<XiuMansion>().unwrap(), XiuMansion::WeiYueYan);
+        assert_eq!("畢".parse::<XiuMansion>().unwrap(), XiuMansion::BiYueWu);
+        assert_eq!("28".parse::<XiuMansion>().unwrap(), XiuMansion::ZhenShuiYin);
+    }
+
+    #[test]
+    fn test_ancient_widths() {
+        // 黄道、赤道古度宿度之和均为周天365¼度
+        for frame in [MansionFrame::Ecliptic, MansionFrame::Equatorial] {
+            let total: f64 = XiuMansion::ALL.iter().map(|m| m.ancient_width(frame)).sum();
+            assert_eq!(total, ANCIENT_CIRCLE);
+        }
+        let table = MansionTable::ancient(MansionFrame::Equatorial, 200.0);
+        assert!((table.width(XiuMansion::JingMuAn) - 33.0 * 360.0 / ANCIENT_CIRCLE).abs() < 1e-9);
+        let placement = table.locate(199.0);
+        assert_eq!(placement.mansion, XiuMansion::ZhenShuiYin);
+        assert!((placement.degree - table.width(XiuMansion::ZhenShuiYin) + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_locate() {
+        // 起点不按宿序排列时（如岁差使觜、参距星先后颠倒），按实际先后划分
+        let mut starts: [f64; 28] = core::array::from_fn(|i| i as f64 * 10.0);
+        starts[19] = 205.0;
+        starts[20] = 195.0;
+        let table = MansionTable::from_starts(MansionFrame::Ecliptic, starts);
+        assert_eq!(table.locate(197.0).mansion, XiuMansion::ShenShuiYuan);
+        assert_eq!(table.locate(206.0).mansion, XiuMansion::ZiHuoHou);
+        assert_eq!(table.width(XiuMansion::ZiHuoHou), 5.0);
+        assert_eq!(table.width(XiuMansion::BiYueWu), 15.0);
+        let placement = table.locate(-5.0);
+        assert_eq!(placement.mansion, XiuMansion::ZhenShuiYin);
+        assert_eq!(placement.degree, 85.0);
+    }
+}
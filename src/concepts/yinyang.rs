@@ -1,8 +1,11 @@
-use serde::Serialize;
-use crate::concepts::traits::ChineseName;
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use crate::concepts::traits::{ChineseName, Pinyin};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::json;
 
 /// 阴阳枚举
-#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum YinYang {
     /// 阳
     Yang,
@@ -28,3 +31,42 @@ impl ChineseName for YinYang {
         }
     }
 }
+
+impl Pinyin for YinYang {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            YinYang::Yang => "yáng",
+            YinYang::Yin => "yīn",
+        }
+    }
+}
+
+impl YinYang {
+    /// 阴阳，按阳、阴排列
+    pub const ALL: [YinYang; 2] = [YinYang::Yang, YinYang::Yin];
+
+    // 可解析的别名：简繁中文与拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            YinYang::Yang => &["阳", "陽", "yang"],
+            YinYang::Yin => &["阴", "陰", "yin"],
+        }
+    }
+}
+
+impl FromStr for YinYang {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "阴阳", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(YinYang, "阴阳");
+
+impl Serialize for YinYang {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let index = Self::ALL.iter().position(|x| x == self).unwrap() + 1;
+        json::serialize_concept(self, index, serializer, |_| Ok(()))
+    }
+}
@@ -1,8 +1,14 @@
 // src/concepts/zhang_sheng.rs - 长生十二神模块
-// Placeholder for长生十二神相关实现
-use serde::Serialize;
+use core::str::FromStr;
+use serde::{Serialize, Serializer};
+use crate::concepts::parse::{self, ParseError, impl_deserialize_from_str};
+use crate::concepts::gan_zhi::{HeavenlyStem, EarthlyBranch};
+use crate::concepts::traits::{ChineseName, Pinyin, Index, Iter, YinYangTrait};
+use crate::concepts::yinyang::YinYang;
+use crate::json;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+/// 长生十二神枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ZhangSheng {
     ChangSheng, // 长生
     MuYu,       // 沐浴
@@ -12,8 +18,273 @@ pub enum ZhangSheng {
     Shuai,      // 衰
     Bing,       // 病
     Si,         // 死
-    Mu,         // 暮
+    Mu,         // 墓
     Jue,        // 绝
     Tai,        // 胎
     Yang,       // 养
-}
\ No newline at end of file
+}
+
+impl ChineseName for ZhangSheng {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            ZhangSheng::ChangSheng => "长生",
+            ZhangSheng::MuYu => "沐浴",
+            ZhangSheng::GuanDai => "冠带",
+            ZhangSheng::LinGuan => "临官",
+            ZhangSheng::DiWang => "帝旺",
+            ZhangSheng::Shuai => "衰",
+            ZhangSheng::Bing => "病",
+            ZhangSheng::Si => "死",
+            ZhangSheng::Mu => "墓",
+            ZhangSheng::Jue => "绝",
+            ZhangSheng::Tai => "胎",
+            ZhangSheng::Yang => "养",
+        }
+    }
+}
+
+impl Pinyin for ZhangSheng {
+    fn pinyin(&self) -> &'static str {
+        match self {
+            ZhangSheng::ChangSheng => "cháng shēng",
+            ZhangSheng::MuYu => "mù yù",
+            ZhangSheng::GuanDai => "guān dài",
+            ZhangSheng::LinGuan => "lín guān",
+            ZhangSheng::DiWang => "dì wàng",
+            ZhangSheng::Shuai => "shuāi",
+            ZhangSheng::Bing => "bìng",
+            ZhangSheng::Si => "sǐ",
+            ZhangSheng::Mu => "mù",
+            ZhangSheng::Jue => "jué",
+            ZhangSheng::Tai => "tāi",
+            ZhangSheng::Yang => "yǎng",
+        }
+    }
+}
+
+impl Index for ZhangSheng {
+    fn from_index(index: usize) -> Self {
+        // 序号按12循环，0视为12（养）
+        Self::ALL[(index + 11) % 12]
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            ZhangSheng::ChangSheng => 1,
+            ZhangSheng::MuYu => 2,
+            ZhangSheng::GuanDai => 3,
+            ZhangSheng::LinGuan => 4,
+            ZhangSheng::DiWang => 5,
+            ZhangSheng::Shuai => 6,
+            ZhangSheng::Bing => 7,
+            ZhangSheng::Si => 8,
+            ZhangSheng::Mu => 9,
+            ZhangSheng::Jue => 10,
+            ZhangSheng::Tai => 11,
+            ZhangSheng::Yang => 12,
+        }
+    }
+}
+
+impl Iter for ZhangSheng {
+    type Item = Self;
+
+    fn next(&self) -> Self::Item {
+        Self::from_index(self.index() + 1)
+    }
+
+    fn prev(&self) -> Self::Item {
+        Self::from_index(self.index() + 11)
+    }
+}
+
+impl ZhangSheng {
+    /// 十二长生，按长生、沐浴、冠带……排列
+    pub const ALL: [ZhangSheng; 12] = [
+        ZhangSheng::ChangSheng, ZhangSheng::MuYu, ZhangSheng::GuanDai, ZhangSheng::LinGuan,
+        ZhangSheng::DiWang, ZhangSheng::Shuai, ZhangSheng::Bing, ZhangSheng::Si,
+        ZhangSheng::Mu, ZhangSheng::Jue, ZhangSheng::Tai, ZhangSheng::Yang,
+    ];
+
+    // 可解析的别名：简繁中文与拼音
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            ZhangSheng::ChangSheng => &["长生", "長生", "changsheng"],
+            ZhangSheng::MuYu => &["沐浴", "muyu"],
+            ZhangSheng::GuanDai => &["冠带", "冠帶", "guandai"],
+            ZhangSheng::LinGuan => &["临官", "臨官", "建禄", "建祿", "linguan"],
+            ZhangSheng::DiWang => &["帝旺", "diwang"],
+            ZhangSheng::Shuai => &["衰", "shuai"],
+            ZhangSheng::Bing => &["病", "bing"],
+            ZhangSheng::Si => &["死", "si"],
+            ZhangSheng::Mu => &["墓", "mu"],
+            ZhangSheng::Jue => &["绝", "絕", "jue"],
+            ZhangSheng::Tai => &["胎", "tai"],
+            ZhangSheng::Yang => &["养", "養", "yang"],
+        }
+    }
+
+    /// 按默认规则获取天干在地支上的十二长生状态
+    pub fn from_stem_branch(stem: HeavenlyStem, branch: EarthlyBranch) -> Self {
+        ZhangShengRule::default().stage(stem, branch)
+    }
+
+    /// 按默认规则反查天干处于该状态的地支
+    pub fn branch_of(&self, stem: HeavenlyStem) -> EarthlyBranch {
+        ZhangShengRule::default().branch(stem, *self)
+    }
+}
+
+impl FromStr for ZhangSheng {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_by_alias(s, "十二长生", &Self::ALL, Self::aliases)
+    }
+}
+
+impl_deserialize_from_str!(ZhangSheng, "十二长生");
+
+impl Serialize for ZhangSheng {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_concept(self, self.index(), serializer, |_| Ok(()))
+    }
+}
+
+/// 阴干长生的排法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum YinStemRule {
+    /// 阳顺阴逆：阴干从其长生之位逆行
+    Reverse,
+    /// 阴阳同生同死：阴干与同五行的阳干同宫顺行
+    FollowYang,
+}
+
+/// 土的寄生方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum EarthRule {
+    /// 火土同宫：戊寄丙、己寄丁
+    WithFire,
+    /// 水土同宫：戊寄壬、己寄癸
+    WithWater,
+}
+
+/// 长生十二神的排布规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ZhangShengRule {
+    /// 阴干排法
+    pub yin_stem: YinStemRule,
+    /// 土的寄生方式
+    pub earth: EarthRule,
+}
+
+impl Default for ZhangShengRule {
+    /// 子平通行排法：阳顺阴逆、火土同宫
+    fn default() -> Self {
+        Self {
+            yin_stem: YinStemRule::Reverse,
+            earth: EarthRule::WithFire,
+        }
+    }
+}
+
+impl ZhangShengRule {
+    /// 创建排布规则
+    pub fn new(yin_stem: YinStemRule, earth: EarthRule) -> Self {
+        Self { yin_stem, earth }
+    }
+
+    /// 获取天干在地支上的十二长生状态
+    pub fn stage(&self, stem: HeavenlyStem, branch: EarthlyBranch) -> ZhangSheng {
+        let start = self.chang_sheng_branch(stem).index();
+        let offset = if self.is_forward(stem) {
+            (branch.index() + 12 - start) % 12
+        } else {
+            (start + 12 - branch.index()) % 12
+        };
+        ZhangSheng::from_index(offset + 1)
+    }
+
+    /// 根据天干和十二长生状态反查所在的地支，如甲之帝旺在卯
+    pub fn branch(&self, stem: HeavenlyStem, stage: ZhangSheng) -> EarthlyBranch {
+        let start = self.chang_sheng_branch(stem).index();
+        let offset = stage.index() - 1;
+        if self.is_forward(stem) {
+            EarthlyBranch::from_index(start + offset)
+        } else {
+            EarthlyBranch::from_index(start + 12 - offset)
+        }
+    }
+
+    /// 十二长生是否顺行
+    fn is_forward(&self, stem: HeavenlyStem) -> bool {
+        stem.yinyang() == YinYang::Yang || self.yin_stem == YinStemRule::FollowYang
+    }
+
+    /// 获取天干的长生之位
+    fn chang_sheng_branch(&self, stem: HeavenlyStem) -> EarthlyBranch {
+        // 阴阳同生同死时，阴干借用同五行阳干的长生之位
+        let stem = match (self.yin_stem, stem.yinyang()) {
+            (YinStemRule::FollowYang, YinYang::Yin) => stem.prev(),
+            _ => stem,
+        };
+        match (stem, self.earth) {
+            (HeavenlyStem::Jia, _) => EarthlyBranch::Hai,
+            (HeavenlyStem::Yi, _) => EarthlyBranch::Wu,
+            (HeavenlyStem::Bing, _) | (HeavenlyStem::Wu, EarthRule::WithFire) => EarthlyBranch::Yin,
+            (HeavenlyStem::Ding, _) | (HeavenlyStem::Ji, EarthRule::WithFire) => EarthlyBranch::You,
+            (HeavenlyStem::Geng, _) => EarthlyBranch::Si,
+            (HeavenlyStem::Xin, _) => EarthlyBranch::Zi,
+            (HeavenlyStem::Ren, _) | (HeavenlyStem::Wu, EarthRule::WithWater) => EarthlyBranch::Shen,
+            (HeavenlyStem::Gui, _) | (HeavenlyStem::Ji, EarthRule::WithWater) => EarthlyBranch::Mao,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_rule() {
+        assert_eq!(ZhangSheng::from_stem_branch(HeavenlyStem::Jia, EarthlyBranch::Hai), ZhangSheng::ChangSheng);
+        assert_eq!(ZhangSheng::from_stem_branch(HeavenlyStem::Jia, EarthlyBranch::Mao), ZhangSheng::DiWang);
+        assert_eq!(ZhangSheng::from_stem_branch(HeavenlyStem::Yi, EarthlyBranch::Yin), ZhangSheng::DiWang);
+        assert_eq!(ZhangSheng::from_stem_branch(HeavenlyStem::Yi, EarthlyBranch::Xu), ZhangSheng::Mu);
+        assert_eq!(ZhangSheng::from_stem_branch(HeavenlyStem::Wu, EarthlyBranch::Wu), ZhangSheng::DiWang);
+        assert_eq!(ZhangSheng::from_stem_branch(HeavenlyStem::Gui, EarthlyBranch::Zi), ZhangSheng::LinGuan);
+    }
+
+    #[test]
+    fn test_from_index() {
+        // 序号循环，0为养，13为长生
+        assert_eq!(ZhangSheng::from_index(0), ZhangSheng::Yang);
+        assert_eq!(ZhangSheng::from_index(13), ZhangSheng::ChangSheng);
+        for state in ZhangSheng::ALL {
+            assert_eq!(ZhangSheng::from_index(state.index()), state);
+        }
+    }
+
+    #[test]
+    fn test_inverse_lookup() {
+        assert_eq!(ZhangSheng::DiWang.branch_of(HeavenlyStem::Jia), EarthlyBranch::Mao);
+        assert_eq!(ZhangSheng::ChangSheng.branch_of(HeavenlyStem::Xin), EarthlyBranch::Zi);
+        let rule = ZhangShengRule::new(YinStemRule::FollowYang, EarthRule::WithWater);
+        for stem in 1..=10 {
+            let stem = HeavenlyStem::from_index(stem);
+            for branch in 1..=12 {
+                let branch = EarthlyBranch::from_index(branch);
+                assert_eq!(rule.branch(stem, rule.stage(stem, branch)), branch);
+                assert_eq!(ZhangSheng::from_stem_branch(stem, branch).branch_of(stem), branch);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rule_variants() {
+        let rule = ZhangShengRule::new(YinStemRule::FollowYang, EarthRule::WithWater);
+        assert_eq!(rule.stage(HeavenlyStem::Yi, EarthlyBranch::Hai), ZhangSheng::ChangSheng);
+        assert_eq!(rule.stage(HeavenlyStem::Wu, EarthlyBranch::Shen), ZhangSheng::ChangSheng);
+        assert_eq!(rule.stage(HeavenlyStem::Ji, EarthlyBranch::Zi), ZhangSheng::DiWang);
+    }
+}
@@ -1,5 +1,13 @@
-use serde::Serialize;
-use std::fmt;
+use serde::{Deserialize, Serialize, Serializer};
+use serde::ser::SerializeMap;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use core::sync::atomic::{AtomicU8, Ordering};
+use alloc::format;
+use alloc::string::{String, ToString};
+use crate::concepts::traits::{ChineseName, Pinyin};
 
 #[derive(Debug, Serialize)]
 pub struct MingbuError {
@@ -13,7 +21,87 @@ impl fmt::Display for MingbuError {
     }
 }
 
-impl std::error::Error for MingbuError {}
+impl core::error::Error for MingbuError {}
+
+/// 概念类型（天干、地支、五行等）在 JSON 中的表示方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConceptStyle {
+    /// 英文变体名，如 "Jia"；六十甲子为 {stem, branch} 对象
+    #[default]
+    Name,
+    /// 中文，如 "甲"
+    Chinese,
+    /// 带声调拼音，如 "jiǎ"
+    Pinyin,
+    /// 从1开始的序号，如 1
+    Index,
+    /// 包含名称、中文、拼音、序号及五行、阴阳、纳音等属性的对象
+    Rich,
+}
+
+/// JSON 输出选项
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct JsonOptions {
+    /// 概念类型的表示方式
+    #[serde(default)]
+    pub style: ConceptStyle,
+}
+
+impl JsonOptions {
+    /// 以指定的概念表示方式创建选项
+    pub fn with_style(style: ConceptStyle) -> Self {
+        Self { style }
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    // 序列化期间生效的概念表示方式，serde 无法传递上下文，因此由 to_json_with 临时设置
+    static CONCEPT_STYLE: Cell<ConceptStyle> = const { Cell::new(ConceptStyle::Name) };
+}
+
+// no_std 下没有线程局部存储，改用全局原子变量（嵌入式目标通常为单线程）
+#[cfg(not(feature = "std"))]
+static CONCEPT_STYLE: AtomicU8 = AtomicU8::new(ConceptStyle::Name as u8);
+
+#[cfg(not(feature = "std"))]
+const STYLES: [ConceptStyle; 5] = [
+    ConceptStyle::Name,
+    ConceptStyle::Chinese,
+    ConceptStyle::Pinyin,
+    ConceptStyle::Index,
+    ConceptStyle::Rich,
+];
+
+// 设置概念表示方式，返回之前的设置
+fn replace_style(style: ConceptStyle) -> ConceptStyle {
+    #[cfg(feature = "std")]
+    return CONCEPT_STYLE.with(|current| current.replace(style));
+    // 只用 load/store：thumbv6m 等目标没有原子的 swap
+    #[cfg(not(feature = "std"))]
+    {
+        let previous = STYLES[CONCEPT_STYLE.load(Ordering::Relaxed) as usize];
+        CONCEPT_STYLE.store(style as u8, Ordering::Relaxed);
+        previous
+    }
+}
+
+// 序列化结束（包括 panic）时恢复之前的表示方式
+struct StyleGuard(ConceptStyle);
+
+impl Drop for StyleGuard {
+    fn drop(&mut self) {
+        replace_style(self.0);
+    }
+}
+
+/// 当前生效的概念表示方式
+pub(crate) fn concept_style() -> ConceptStyle {
+    #[cfg(feature = "std")]
+    return CONCEPT_STYLE.with(|style| style.get());
+    #[cfg(not(feature = "std"))]
+    return STYLES[CONCEPT_STYLE.load(Ordering::Relaxed) as usize];
+}
 
 pub fn to_json<T: Serialize>(result: &T) -> Result<String, MingbuError> {
     serde_json::to_string(result)
@@ -23,3 +111,67 @@ pub fn to_json<T: Serialize>(result: &T) -> Result<String, MingbuError> {
         })
 }
 
+/// 按输出选项序列化为 JSON
+pub fn to_json_with<T: Serialize>(result: &T, options: &JsonOptions) -> Result<String, MingbuError> {
+    let _guard = StyleGuard(replace_style(options.style));
+    to_json(result)
+}
+
+/// 按当前表示方式序列化概念类型
+/// extra 用于在 Rich 表示中追加该类型特有的属性
+pub(crate) fn serialize_concept<T, S, F>(
+    value: &T,
+    index: usize,
+    serializer: S,
+    extra: F,
+) -> Result<S::Ok, S::Error>
+where
+    T: fmt::Debug + ChineseName + Pinyin,
+    S: Serializer,
+    F: FnOnce(&mut S::SerializeMap) -> Result<(), S::Error>,
+{
+    match concept_style() {
+        ConceptStyle::Name => serializer.serialize_str(&format!("{:?}", value)),
+        ConceptStyle::Chinese => serializer.serialize_str(value.chinese_name()),
+        ConceptStyle::Pinyin => serializer.serialize_str(value.pinyin()),
+        ConceptStyle::Index => serializer.serialize_u64(index as u64),
+        ConceptStyle::Rich => {
+            let mut map = serializer.serialize_map(None)?;
+            map.serialize_entry("name", &format!("{:?}", value))?;
+            map.serialize_entry("chinese", value.chinese_name())?;
+            map.serialize_entry("pinyin", value.pinyin())?;
+            map.serialize_entry("index", &index)?;
+            extra(&mut map)?;
+            map.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::concepts::gan_zhi::{EarthlyBranch, GanZhi, HeavenlyStem};
+
+    #[test]
+    fn test_concept_styles() {
+        let gz = GanZhi::new(HeavenlyStem::Geng, EarthlyBranch::Wu).unwrap();
+        let render = |style| to_json_with(&gz, &JsonOptions::with_style(style)).unwrap();
+
+        assert_eq!(render(ConceptStyle::Name), r#"{"stem":"Geng","branch":"Wu"}"#);
+        assert_eq!(render(ConceptStyle::Chinese), r#""庚午""#);
+        assert_eq!(render(ConceptStyle::Pinyin), r#""gēng wǔ""#);
+        assert_eq!(render(ConceptStyle::Index), "7");
+
+        let rich: serde_json::Value = serde_json::from_str(&render(ConceptStyle::Rich)).unwrap();
+        assert_eq!(rich["chinese"], "庚午");
+        assert_eq!(rich["stem"]["element"]["chinese"], "金");
+        assert_eq!(rich["branch"]["polarity"]["name"], "Yang");
+        assert_eq!(rich["nayin"]["name"], "路旁土");
+
+        // 各种表示都能读回，且不影响之后的默认输出
+        for style in [ConceptStyle::Name, ConceptStyle::Chinese, ConceptStyle::Pinyin, ConceptStyle::Index, ConceptStyle::Rich] {
+            assert_eq!(serde_json::from_str::<GanZhi>(&render(style)).unwrap(), gz);
+        }
+        assert_eq!(to_json(&gz.stem).unwrap(), r#""Geng""#);
+    }
+}
@@ -1,4 +1,9 @@
 // 公共 API 入口
+// 关闭 std 特性时以 no_std + alloc 编译，仅保留不依赖星历的纯符号部分
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod calendar;
 pub mod concepts;
 pub mod metaphysics;
@@ -6,4 +11,7 @@ pub mod utils;
 pub mod json;
 
 // 重新导出常用函数
-pub use metaphysics::{ba_zi_json, qi_zheng_json};
\ No newline at end of file
+pub use metaphysics::{ba_zi_json, ba_zi_json_with, liu_yao_json, liu_yao_json_with};
+#[cfg(feature = "ephemeris")]
+pub use metaphysics::{qi_zheng_json, qi_zheng_json_with, xuan_kong_json, xuan_kong_json_with};
+pub use json::{ConceptStyle, JsonOptions};
\ No newline at end of file
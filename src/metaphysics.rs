@@ -1,18 +1,86 @@
 pub mod ba_zi;
+// 七政四余经 calendar::ephemeris 调用 Swiss Ephemeris
+#[cfg(feature = "ephemeris")]
 pub mod qi_zheng;
+pub mod liu_yao;
+pub mod mei_hua;
+pub mod ba_zhai;
+// 玄空飞星依赖 Calculate 的二十四山度数划分
+#[cfg(feature = "ephemeris")]
+pub mod xuan_kong;
 
+use alloc::string::{String, ToString};
+use crate::concepts::gan_zhi::GanZhi;
+use crate::concepts::hexagram::Hexagram;
+#[cfg(feature = "ephemeris")]
+use crate::concepts::nine_star::NineStar;
 use crate::json;
 
 pub fn ba_zi_json(year: i32, month: u32, day: u32, hour: u32) -> Result<String, json::MingbuError> {
+    ba_zi_json_with(year, month, day, hour, &json::JsonOptions::default())
+}
+
+/// 按输出选项生成八字 JSON
+pub fn ba_zi_json_with(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    options: &json::JsonOptions,
+) -> Result<String, json::MingbuError> {
     let bazi = ba_zi::BaZi::from_solar_date(year, month, day, hour)
         .map_err(|e| json::MingbuError {
             code: "BAZI_ERROR",
             message: e.to_string(),
         })?;
-    json::to_json(&bazi)
+    json::to_json_with(&bazi, options)
+}
+
+#[cfg(feature = "ephemeris")]
+pub fn qi_zheng_json(julian_day: f64, location: qi_zheng::Location, zi_qi: qi_zheng::ZiQiModel) -> Result<String, json::MingbuError> {
+    qi_zheng_json_with(julian_day, location, &qi_zheng::QiZhengOptions::new(zi_qi), &json::JsonOptions::default())
+}
+
+/// 按排盘选项与输出选项生成七政四余 JSON，julian_day 为世界时儒略日
+#[cfg(feature = "ephemeris")]
+pub fn qi_zheng_json_with(
+    julian_day: f64,
+    location: qi_zheng::Location,
+    pan_options: &qi_zheng::QiZhengOptions,
+    options: &json::JsonOptions,
+) -> Result<String, json::MingbuError> {
+    let pan = qi_zheng::QiZhengPan::from_julian_day_with(julian_day, location, pan_options)
+        .map_err(|e| json::MingbuError {
+            code: "QI_ZHENG_ERROR",
+            message: e.to_string(),
+        })?;
+    json::to_json_with(&pan, options)
+}
+
+pub fn liu_yao_json(hexagram: Hexagram, moving: &[usize], month: GanZhi, day: GanZhi) -> Result<String, json::MingbuError> {
+    liu_yao_json_with(hexagram, moving, month, day, &json::JsonOptions::default())
+}
+
+/// 按输出选项生成六爻纳甲盘 JSON
+pub fn liu_yao_json_with(
+    hexagram: Hexagram,
+    moving: &[usize],
+    month: GanZhi,
+    day: GanZhi,
+    options: &json::JsonOptions,
+) -> Result<String, json::MingbuError> {
+    let chart = liu_yao::LiuYao::new(hexagram, moving, month, day)?;
+    json::to_json_with(&chart, options)
+}
+
+#[cfg(feature = "ephemeris")]
+pub fn xuan_kong_json(period: NineStar, facing: f64) -> Result<String, json::MingbuError> {
+    xuan_kong_json_with(period, facing, &json::JsonOptions::default())
 }
 
-pub fn qi_zheng_json(julian_day: f64) -> Result<String, json::MingbuError> {
-    let pan = qi_zheng::QiZhengPan::from_julian_day(julian_day);
-    json::to_json(&pan)
-}
\ No newline at end of file
+/// 按输出选项生成玄空飞星盘 JSON
+#[cfg(feature = "ephemeris")]
+pub fn xuan_kong_json_with(period: NineStar, facing: f64, options: &json::JsonOptions) -> Result<String, json::MingbuError> {
+    let chart = xuan_kong::XuanKong::new(period, facing)?;
+    json::to_json_with(&chart, options)
+}
@@ -0,0 +1,324 @@
+// src/metaphysics/ba_zhai.rs - 八宅
+// 由出生年与性别求命卦，由坐山求宅卦，按大游年排出八方的吉凶游星
+use serde::Serialize;
+#[cfg(feature = "ephemeris")]
+use crate::calendar::ephemeris::EphemerisError;
+#[cfg(feature = "ephemeris")]
+use crate::calendar::jie_qi;
+use crate::concepts::bagua::Trigram;
+use crate::concepts::direction::Direction;
+use crate::concepts::mountain::Mountain;
+use crate::concepts::nine_star::NineStar;
+use crate::concepts::traits::{ChineseName, Index, WuXingTrait};
+use crate::concepts::wu_xing::WuXing;
+
+/// 性别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Gender {
+    /// 男
+    Male,
+    /// 女
+    Female,
+}
+
+impl ChineseName for Gender {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Gender::Male => "男",
+            Gender::Female => "女",
+        }
+    }
+}
+
+/// 东四、西四：坎离震巽为东四，乾坤艮兑为西四
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Group {
+    /// 东四命、东四宅
+    East,
+    /// 西四命、西四宅
+    West,
+}
+
+impl ChineseName for Group {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Group::East => "东四",
+            Group::West => "西四",
+        }
+    }
+}
+
+impl Group {
+    /// 卦所属的东西四
+    pub fn of(trigram: Trigram) -> Self {
+        match trigram {
+            Trigram::Kan | Trigram::Li | Trigram::Zhen | Trigram::Xun => Group::East,
+            Trigram::Qian | Trigram::Kun | Trigram::Gen | Trigram::Dui => Group::West,
+        }
+    }
+}
+
+/// 大游年八星，前四吉、后四凶
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum YouNian {
+    /// 生气（贪狼）
+    ShengQi,
+    /// 天医（巨门）
+    TianYi,
+    /// 延年（武曲）
+    YanNian,
+    /// 伏位（辅弼）
+    FuWei,
+    /// 绝命（破军）
+    JueMing,
+    /// 五鬼（廉贞）
+    WuGui,
+    /// 六煞（文曲）
+    LiuSha,
+    /// 祸害（禄存）
+    HuoHai,
+}
+
+impl ChineseName for YouNian {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            YouNian::ShengQi => "生气",
+            YouNian::TianYi => "天医",
+            YouNian::YanNian => "延年",
+            YouNian::FuWei => "伏位",
+            YouNian::JueMing => "绝命",
+            YouNian::WuGui => "五鬼",
+            YouNian::LiuSha => "六煞",
+            YouNian::HuoHai => "祸害",
+        }
+    }
+}
+
+impl WuXingTrait for YouNian {
+    fn wuxing(&self) -> WuXing {
+        match self {
+            YouNian::ShengQi | YouNian::FuWei => WuXing::Wood,
+            YouNian::TianYi | YouNian::HuoHai => WuXing::Earth,
+            YouNian::YanNian | YouNian::JueMing => WuXing::Metal,
+            YouNian::WuGui => WuXing::Fire,
+            YouNian::LiuSha => WuXing::Water,
+        }
+    }
+}
+
+impl YouNian {
+    /// 九星名：贪狼、巨门、武曲、辅弼、破军、廉贞、文曲、禄存
+    pub fn star_name(&self) -> &'static str {
+        match self {
+            YouNian::ShengQi => "贪狼",
+            YouNian::TianYi => "巨门",
+            YouNian::YanNian => "武曲",
+            YouNian::FuWei => "辅弼",
+            YouNian::JueMing => "破军",
+            YouNian::WuGui => "廉贞",
+            YouNian::LiuSha => "文曲",
+            YouNian::HuoHai => "禄存",
+        }
+    }
+
+    /// 是否为吉星
+    pub fn is_auspicious(&self) -> bool {
+        matches!(self, YouNian::ShengQi | YouNian::TianYi | YouNian::YanNian | YouNian::FuWei)
+    }
+
+    /// 以base为本卦，other方所得的游星
+    /// 按大游年变爻：上变生气、中变五鬼、下变延年、中变六煞、上变祸害、中变天医、下变绝命
+    pub fn between(base: Trigram, other: Trigram) -> Self {
+        let (from, to) = (base.lines(), other.lines());
+        let changed = [0, 1, 2].map(|i| from[i] != to[i]);
+        match changed {
+            [false, false, false] => YouNian::FuWei,
+            [false, false, true] => YouNian::ShengQi,
+            [false, true, true] => YouNian::WuGui,
+            [true, true, true] => YouNian::YanNian,
+            [true, false, true] => YouNian::LiuSha,
+            [true, false, false] => YouNian::HuoHai,
+            [true, true, false] => YouNian::TianYi,
+            [false, true, false] => YouNian::JueMing,
+        }
+    }
+}
+
+/// 某一方位的游星
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DirectionStar {
+    /// 方位
+    pub direction: Direction,
+    /// 后天八卦中位于该方位的卦
+    pub trigram: Trigram,
+    /// 游星
+    pub you_nian: YouNian,
+}
+
+/// 以本卦排出八方游星，从北开始按顺时针排列
+pub fn directions(base: Trigram) -> [DirectionStar; 8] {
+    Direction::ALL.map(|direction| {
+        let trigram = Trigram::from_hou_tian_direction(direction);
+        DirectionStar { direction, trigram, you_nian: YouNian::between(base, trigram) }
+    })
+}
+
+/// 命卦
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MingGua {
+    /// 出生年，以立春为岁首
+    pub year: i32,
+    /// 性别
+    pub gender: Gender,
+    /// 命星数
+    pub star: NineStar,
+    /// 命卦，五黄寄宫：男寄坤、女寄艮
+    pub trigram: Trigram,
+    /// 东四命或西四命
+    pub group: Group,
+    /// 八方游星
+    pub directions: [DirectionStar; 8],
+}
+
+impl MingGua {
+    /// 由出生年（已按立春定岁首）与性别求命卦，由出生时刻推算见 from_julian_day
+    /// 男命即该年入中的年紫白星，女命与男命之数相加为十五（以九取余）
+    pub fn new(year: i32, gender: Gender) -> Self {
+        let male = NineStar::of_year(year);
+        let star = match gender {
+            Gender::Male => male,
+            Gender::Female => NineStar::from_index(15 - male.index()),
+        };
+        let trigram = star.home_palace().trigram().unwrap_or(match gender {
+            Gender::Male => Trigram::Kun,
+            Gender::Female => Trigram::Gen,
+        });
+        MingGua {
+            year,
+            gender,
+            star,
+            trigram,
+            group: Group::of(trigram),
+            directions: directions(trigram),
+        }
+    }
+
+    /// 由出生时刻（世界时儒略日）与性别求命卦，以当年立春（太阳黄经315°）交节时刻为岁首
+    #[cfg(feature = "ephemeris")]
+    pub fn from_julian_day(julian_day: f64, gender: Gender) -> Result<Self, EphemerisError> {
+        Ok(Self::new(jie_qi::year(julian_day)?, gender))
+    }
+
+    /// 命与宅是否同属东四或西四
+    pub fn suits(&self, house: &House) -> bool {
+        self.group == house.group
+    }
+
+    /// 某一方位的游星
+    pub fn star_at(&self, direction: Direction) -> YouNian {
+        self.directions[direction.index() - 1].you_nian
+    }
+}
+
+/// 宅卦：以坐山所在之卦为宅卦
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct House {
+    /// 立向度数（正北为0，顺时针）
+    pub facing: f64,
+    /// 向首所在的山
+    pub facing_mountain: Mountain,
+    /// 坐山
+    pub sitting_mountain: Mountain,
+    /// 宅卦
+    pub trigram: Trigram,
+    /// 东四宅或西四宅
+    pub group: Group,
+    /// 八方游星
+    pub directions: [DirectionStar; 8],
+}
+
+impl House {
+    /// 由立向度数排宅，如坎宅（坐子午向）
+    pub fn new(facing: f64) -> Self {
+        let (facing_mountain, _) = Mountain::from_degree(facing);
+        let sitting_mountain = facing_mountain.opposite();
+        let trigram = sitting_mountain.trigram();
+        House {
+            facing,
+            facing_mountain,
+            sitting_mountain,
+            trigram,
+            group: Group::of(trigram),
+            directions: directions(trigram),
+        }
+    }
+
+    /// 某一方位的游星
+    pub fn star_at(&self, direction: Direction) -> YouNian {
+        self.directions[direction.index() - 1].you_nian
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_you_nian() {
+        // 坎：生气巽、天医震、延年离、伏位坎、绝命坤、五鬼艮、六煞乾、祸害兑
+        let expected = [
+            (Trigram::Xun, YouNian::ShengQi), (Trigram::Zhen, YouNian::TianYi),
+            (Trigram::Li, YouNian::YanNian), (Trigram::Kan, YouNian::FuWei),
+            (Trigram::Kun, YouNian::JueMing), (Trigram::Gen, YouNian::WuGui),
+            (Trigram::Qian, YouNian::LiuSha), (Trigram::Dui, YouNian::HuoHai),
+        ];
+        for (trigram, star) in expected {
+            assert_eq!(YouNian::between(Trigram::Kan, trigram), star);
+        }
+        // 同组之卦互为吉星，异组互为凶星
+        for base in Trigram::ALL {
+            for other in Trigram::ALL {
+                let same = Group::of(base) == Group::of(other);
+                assert_eq!(YouNian::between(base, other).is_auspicious(), same);
+                assert_eq!(YouNian::between(base, other), YouNian::between(other, base));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ming_gua() {
+        // 1984年男七兑、女八艮
+        let male = MingGua::new(1984, Gender::Male);
+        assert_eq!((male.star, male.trigram, male.group), (NineStar::QiChi, Trigram::Dui, Group::West));
+        let female = MingGua::new(1984, Gender::Female);
+        assert_eq!((female.star, female.trigram), (NineStar::BaBai, Trigram::Gen));
+        // 五黄寄宫：1986年男五寄坤，1981年女五寄艮
+        assert_eq!(MingGua::new(1986, Gender::Male).trigram, Trigram::Kun);
+        assert_eq!(MingGua::new(1981, Gender::Female).trigram, Trigram::Gen);
+        assert_eq!(MingGua::new(1990, Gender::Male).trigram, Trigram::Kan);
+        assert_eq!(male.star_at(Direction::NorthWest), YouNian::ShengQi);
+    }
+
+    #[cfg(feature = "ephemeris")]
+    #[test]
+    fn test_ming_gua_from_julian_day() {
+        // 2024年立春在2月4日北京时间16时27分：当日午时生者属2023年四绿巽，次日生者属2024年三碧震
+        let before = MingGua::from_julian_day(2460344.5 + 4.0 / 24.0, Gender::Male).unwrap();
+        assert_eq!((before.year, before.trigram), (2023, Trigram::Xun));
+        let after = MingGua::from_julian_day(2460345.5 + 4.0 / 24.0, Gender::Male).unwrap();
+        assert_eq!((after.year, after.trigram), (2024, Trigram::Zhen));
+    }
+
+    #[test]
+    fn test_house() {
+        // 坐子向午为坎宅，东四宅
+        let house = House::new(180.0);
+        assert_eq!(house.sitting_mountain, Mountain::Zi);
+        assert_eq!(house.trigram, Trigram::Kan);
+        assert_eq!(house.group, Group::East);
+        assert_eq!(house.star_at(Direction::SouthEast), YouNian::ShengQi);
+        assert_eq!(house.star_at(Direction::North), YouNian::FuWei);
+        assert!(MingGua::new(1990, Gender::Male).suits(&house));
+        assert!(!MingGua::new(1984, Gender::Male).suits(&house));
+    }
+}
@@ -1,9 +1,26 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
 use crate::concepts::gan_zhi::GanZhi;
 use crate::concepts::traits::WuXingTrait;
 use crate::concepts::wu_xing::WuXing;
+use crate::concepts::gan_zhi::EarthlyBranch;
+use crate::concepts::shen_sha::{Pillar, ShenShaCatalog, ShenShaHit};
+use crate::concepts::zhang_sheng::{ZhangSheng, ZhangShengRule};
 use crate::calendar::chinese::DateTime;
 use serde::Serialize;
 
+/// 四柱空亡的查找结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct KongWang {
+    /// 起空亡的柱（日柱或年柱）
+    pub anchor: Pillar,
+    /// 空亡的两个地支
+    pub branches: [EarthlyBranch; 2],
+    /// 地支落入空亡的柱
+    pub pillars: Vec<Pillar>,
+}
+
 #[derive(Serialize)]
 pub struct BaZi {
     pub year: GanZhi,
@@ -14,7 +31,7 @@ pub struct BaZi {
 }
 
 impl BaZi {
-    pub fn from_solar_date(year: i32, month: u32, day: u32, hour: u32) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_solar_date(year: i32, month: u32, day: u32, hour: u32) -> Result<Self, Box<dyn core::error::Error>> {
         // 使用calendar模块从公历日期获取农历干支
         let datetime = DateTime::from_solar_date(year, month, day, hour)?;
         let (year_gan_zhi, month_gan_zhi, day_gan_zhi, hour_gan_zhi) = datetime.get_gan_zhi();
@@ -43,4 +60,53 @@ impl BaZi {
             day_master,
         }
     }
-}
\ No newline at end of file
+    
+    /// 日主在年、月、日、时四柱地支上的十二长生状态（默认规则）
+    pub fn zhang_sheng(&self) -> [ZhangSheng; 4] {
+        self.zhang_sheng_with(&ZhangShengRule::default())
+    }
+
+    /// 按指定规则获取日主在四柱地支上的十二长生状态
+    pub fn zhang_sheng_with(&self, rule: &ZhangShengRule) -> [ZhangSheng; 4] {
+        let day_stem = *self.day.stem();
+        [self.year, self.month, self.day, self.hour]
+            .map(|pillar| rule.stage(day_stem, *pillar.branch()))
+    }
+
+    /// 按神煞规则表查出四柱所带的神煞
+    pub fn shen_sha(&self, catalog: &ShenShaCatalog) -> Vec<ShenShaHit> {
+        catalog.evaluate(&[self.year, self.month, self.day, self.hour])
+    }
+
+    /// 获取指定位置的柱
+    pub fn pillar(&self, pillar: Pillar) -> GanZhi {
+        match pillar {
+            Pillar::Year => self.year,
+            Pillar::Month => self.month,
+            Pillar::Day => self.day,
+            Pillar::Hour => self.hour,
+        }
+    }
+
+    /// 查找四柱空亡：以日柱起，by_year 为真时另以年柱起
+    pub fn kong_wang(&self, by_year: bool) -> Vec<KongWang> {
+        let mut anchors = vec![Pillar::Day];
+        if by_year {
+            anchors.push(Pillar::Year);
+        }
+        anchors
+            .into_iter()
+            .map(|anchor| {
+                let xun = self.pillar(anchor);
+                KongWang {
+                    anchor,
+                    branches: xun.kong_wang(),
+                    pillars: Pillar::ALL
+                        .into_iter()
+                        .filter(|pillar| *pillar != anchor && xun.is_kong_wang(self.pillar(*pillar).branch()))
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+}
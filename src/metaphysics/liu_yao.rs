@@ -0,0 +1,511 @@
+// src/metaphysics/liu_yao.rs - 六爻纳甲排盘
+// 由本卦、动爻与占卜时的月日干支，排出纳甲、六亲、世应、八宫、六神、伏神与空亡
+use alloc::format;
+use alloc::vec::Vec;
+use serde::Serialize;
+use crate::calendar::lunar::LunarDate;
+use crate::concepts::bagua::Trigram;
+use crate::concepts::gan_zhi::{EarthlyBranch, GanZhi, HeavenlyStem};
+use crate::concepts::hexagram::Hexagram;
+use crate::concepts::traits::{ChineseName, Index, WuXingTrait};
+use crate::concepts::wu_xing::WuXing;
+use crate::concepts::yinyang::YinYang;
+use crate::json::MingbuError;
+
+/// 六亲，以卦宫五行为"我"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum LiuQin {
+    /// 兄弟：与我同类
+    XiongDi,
+    /// 子孙：我生者
+    ZiSun,
+    /// 妻财：我克者
+    QiCai,
+    /// 官鬼：克我者
+    GuanGui,
+    /// 父母：生我者
+    FuMu,
+}
+
+impl ChineseName for LiuQin {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            LiuQin::XiongDi => "兄弟",
+            LiuQin::ZiSun => "子孙",
+            LiuQin::QiCai => "妻财",
+            LiuQin::GuanGui => "官鬼",
+            LiuQin::FuMu => "父母",
+        }
+    }
+}
+
+impl LiuQin {
+    /// 六亲（实为五类），按兄弟、子孙、妻财、官鬼、父母排列
+    pub const ALL: [LiuQin; 5] = [
+        LiuQin::XiongDi, LiuQin::ZiSun, LiuQin::QiCai, LiuQin::GuanGui, LiuQin::FuMu,
+    ];
+
+    /// 以palace为我，求element所属的六亲
+    pub fn of(palace: WuXing, element: WuXing) -> Self {
+        if element == palace {
+            LiuQin::XiongDi
+        } else if palace.generates() == element {
+            LiuQin::ZiSun
+        } else if palace.overcomes() == element {
+            LiuQin::QiCai
+        } else if element.overcomes() == palace {
+            LiuQin::GuanGui
+        } else {
+            LiuQin::FuMu
+        }
+    }
+}
+
+/// 六神（六兽），由日干起于初爻
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum LiuShen {
+    /// 青龙
+    QingLong,
+    /// 朱雀
+    ZhuQue,
+    /// 勾陈
+    GouChen,
+    /// 螣蛇
+    TengShe,
+    /// 白虎
+    BaiHu,
+    /// 玄武
+    XuanWu,
+}
+
+impl ChineseName for LiuShen {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            LiuShen::QingLong => "青龙",
+            LiuShen::ZhuQue => "朱雀",
+            LiuShen::GouChen => "勾陈",
+            LiuShen::TengShe => "螣蛇",
+            LiuShen::BaiHu => "白虎",
+            LiuShen::XuanWu => "玄武",
+        }
+    }
+}
+
+impl LiuShen {
+    /// 六神，按青龙、朱雀、勾陈、螣蛇、白虎、玄武自下而上排列
+    pub const ALL: [LiuShen; 6] = [
+        LiuShen::QingLong, LiuShen::ZhuQue, LiuShen::GouChen,
+        LiuShen::TengShe, LiuShen::BaiHu, LiuShen::XuanWu,
+    ];
+
+    /// 由日干求初爻的六神：甲乙起青龙，丙丁起朱雀，戊起勾陈，己起螣蛇，庚辛起白虎，壬癸起玄武
+    pub fn first(day_stem: HeavenlyStem) -> Self {
+        match day_stem {
+            HeavenlyStem::Jia | HeavenlyStem::Yi => LiuShen::QingLong,
+            HeavenlyStem::Bing | HeavenlyStem::Ding => LiuShen::ZhuQue,
+            HeavenlyStem::Wu => LiuShen::GouChen,
+            HeavenlyStem::Ji => LiuShen::TengShe,
+            HeavenlyStem::Geng | HeavenlyStem::Xin => LiuShen::BaiHu,
+            HeavenlyStem::Ren | HeavenlyStem::Gui => LiuShen::XuanWu,
+        }
+    }
+}
+
+/// 卦在八宫中的世代
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Generation {
+    /// 本宫（八纯卦），世在上爻
+    BenGong,
+    /// 一世卦
+    YiShi,
+    /// 二世卦
+    ErShi,
+    /// 三世卦
+    SanShi,
+    /// 四世卦
+    SiShi,
+    /// 五世卦
+    WuShi,
+    /// 游魂卦，世在四爻
+    YouHun,
+    /// 归魂卦，世在三爻
+    GuiHun,
+}
+
+impl ChineseName for Generation {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Generation::BenGong => "本宫",
+            Generation::YiShi => "一世",
+            Generation::ErShi => "二世",
+            Generation::SanShi => "三世",
+            Generation::SiShi => "四世",
+            Generation::WuShi => "五世",
+            Generation::YouHun => "游魂",
+            Generation::GuiHun => "归魂",
+        }
+    }
+}
+
+impl Generation {
+    /// 八宫世代，按本宫、一世……五世、游魂、归魂排列
+    pub const ALL: [Generation; 8] = [
+        Generation::BenGong, Generation::YiShi, Generation::ErShi, Generation::SanShi,
+        Generation::SiShi, Generation::WuShi, Generation::YouHun, Generation::GuiHun,
+    ];
+
+    /// 由本宫纯卦变出该世代所需变动的爻
+    fn changed_lines(&self) -> &'static [usize] {
+        match self {
+            Generation::BenGong => &[],
+            Generation::YiShi => &[1],
+            Generation::ErShi => &[1, 2],
+            Generation::SanShi => &[1, 2, 3],
+            Generation::SiShi => &[1, 2, 3, 4],
+            Generation::WuShi => &[1, 2, 3, 4, 5],
+            Generation::YouHun => &[1, 2, 3, 5],
+            Generation::GuiHun => &[5],
+        }
+    }
+
+    /// 世爻位置
+    pub fn shi(&self) -> usize {
+        match self {
+            Generation::BenGong => 6,
+            Generation::YiShi => 1,
+            Generation::ErShi => 2,
+            Generation::SanShi => 3,
+            Generation::SiShi => 4,
+            Generation::WuShi => 5,
+            Generation::YouHun => 4,
+            Generation::GuiHun => 3,
+        }
+    }
+
+    /// 应爻位置，与世爻相隔两爻
+    pub fn ying(&self) -> usize {
+        (self.shi() + 2) % 6 + 1
+    }
+}
+
+/// 求卦所属的八宫与世代
+pub fn palace_of(hexagram: &Hexagram) -> (Trigram, Generation) {
+    Trigram::ALL
+        .iter()
+        .find_map(|palace| {
+            let pure = Hexagram::new(*palace, *palace);
+            Generation::ALL
+                .iter()
+                .find(|generation| pure.bian_gua(generation.changed_lines()) == *hexagram)
+                .map(|generation| (*palace, *generation))
+        })
+        .unwrap()
+}
+
+/// 京房纳甲：返回经卦所纳天干，以及自下而上三爻所纳地支
+/// outer 为 true 时表示外卦（上卦）
+pub fn na_jia(trigram: Trigram, outer: bool) -> (HeavenlyStem, [EarthlyBranch; 3]) {
+    use EarthlyBranch::*;
+    match (trigram, outer) {
+        (Trigram::Qian, false) => (HeavenlyStem::Jia, [Zi, Yin, Chen]),
+        (Trigram::Qian, true) => (HeavenlyStem::Ren, [Wu, Shen, Xu]),
+        (Trigram::Kun, false) => (HeavenlyStem::Yi, [Wei, Si, Mao]),
+        (Trigram::Kun, true) => (HeavenlyStem::Gui, [Chou, Hai, You]),
+        (Trigram::Zhen, false) => (HeavenlyStem::Geng, [Zi, Yin, Chen]),
+        (Trigram::Zhen, true) => (HeavenlyStem::Geng, [Wu, Shen, Xu]),
+        (Trigram::Xun, false) => (HeavenlyStem::Xin, [Chou, Hai, You]),
+        (Trigram::Xun, true) => (HeavenlyStem::Xin, [Wei, Si, Mao]),
+        (Trigram::Kan, false) => (HeavenlyStem::Wu, [Yin, Chen, Wu]),
+        (Trigram::Kan, true) => (HeavenlyStem::Wu, [Shen, Xu, Zi]),
+        (Trigram::Li, false) => (HeavenlyStem::Ji, [Mao, Chou, Hai]),
+        (Trigram::Li, true) => (HeavenlyStem::Ji, [You, Wei, Si]),
+        (Trigram::Gen, false) => (HeavenlyStem::Bing, [Chen, Wu, Shen]),
+        (Trigram::Gen, true) => (HeavenlyStem::Bing, [Xu, Zi, Yin]),
+        (Trigram::Dui, false) => (HeavenlyStem::Ding, [Si, Mao, Chou]),
+        (Trigram::Dui, true) => (HeavenlyStem::Ding, [Hai, You, Wei]),
+    }
+}
+
+/// 一爻所纳的干支及其六亲
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct NaJia {
+    pub stem: HeavenlyStem,
+    pub branch: EarthlyBranch,
+    pub element: WuXing,
+    pub relative: LiuQin,
+}
+
+impl NaJia {
+    // 卦中第position爻（1为初爻）所纳干支，六亲以palace_element为我
+    fn of(hexagram: &Hexagram, position: usize, palace_element: WuXing) -> Self {
+        let (trigram, outer) = if position > 3 {
+            (hexagram.upper, true)
+        } else {
+            (hexagram.lower, false)
+        };
+        let (stem, branches) = na_jia(trigram, outer);
+        let branch = branches[(position - 1) % 3];
+        let element = branch.wuxing();
+        NaJia {
+            stem,
+            branch,
+            element,
+            relative: LiuQin::of(palace_element, element),
+        }
+    }
+}
+
+/// 排盘中的一爻
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Line {
+    /// 爻位，1为初爻，6为上爻
+    pub position: usize,
+    /// 阴爻或阳爻
+    pub yinyang: YinYang,
+    /// 是否为动爻
+    pub moving: bool,
+    /// 纳甲干支与六亲
+    pub na_jia: NaJia,
+    /// 六神
+    pub spirit: LiuShen,
+    /// 是否为世爻
+    pub shi: bool,
+    /// 是否为应爻
+    pub ying: bool,
+    /// 爻支是否落入日空亡
+    pub kong_wang: bool,
+    /// 爻支是否被月建所冲（月破）
+    pub yue_po: bool,
+    /// 伏神：本卦缺少的六亲，取本宫纯卦同位之爻伏于此爻之下
+    pub hidden: Option<NaJia>,
+    /// 动爻所变之爻，六亲仍以本卦卦宫为我
+    pub changed: Option<NaJia>,
+}
+
+/// 起卦结果：本卦与动爻
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Cast {
+    pub hexagram: Hexagram,
+    /// 动爻位置，1为初爻，6为上爻，从小到大排列
+    pub moving: Vec<usize>,
+}
+
+impl Cast {
+    /// 由六次摇卦所得之数起卦，从初爻到上爻
+    /// 6为老阴（动）、7为少阳、8为少阴、9为老阳（动）
+    pub fn from_coins(values: [u8; 6]) -> Result<Self, MingbuError> {
+        let mut lines = [YinYang::Yin; 6];
+        let mut moving = Vec::new();
+        for (i, value) in values.iter().enumerate() {
+            lines[i] = match value {
+                6 | 8 => YinYang::Yin,
+                7 | 9 => YinYang::Yang,
+                _ => {
+                    return Err(MingbuError {
+                        code: "LIU_YAO_ERROR",
+                        message: format!("第{}爻的摇卦数 {} 不在6到9之间", i + 1, value),
+                    })
+                }
+            };
+            if matches!(value, 6 | 9) {
+                moving.push(i + 1);
+            }
+        }
+        Ok(Cast { hexagram: Hexagram::from_lines(lines), moving })
+    }
+
+    /// 以两数起卦：上数除八取余为上卦，下数除八取余为下卦（余数按先天数，0作8），两数之和除六取余为动爻（0作6）
+    pub fn from_numbers(upper: u32, lower: u32) -> Self {
+        let (upper, lower) = (upper as u64, lower as u64);
+        Self::from_sums(upper, lower, upper + lower)
+    }
+
+    /// 以时间起卦：年支数、农历月、日之和为上卦，再加时支数为下卦与动爻；闰月按所闰之月计数
+    pub fn from_time(date: &LunarDate, hour: EarthlyBranch) -> Self {
+        let upper = date.year_branch().index() as u64 + date.month as u64 + date.day as u64;
+        let lower = upper + hour.index() as u64;
+        Self::from_sums(upper, lower, lower)
+    }
+
+    /// 由三个和数起卦：上数、下数除八取余为上下卦（余数按先天数，0作8），动数除六取余为动爻（0作6）
+    pub(crate) fn from_sums(upper: u64, lower: u64, moving: u64) -> Self {
+        Cast {
+            hexagram: Hexagram::new(
+                Trigram::from_index((upper % 8) as usize),
+                Trigram::from_index((lower % 8) as usize),
+            ),
+            moving: alloc::vec![((moving + 5) % 6 + 1) as usize],
+        }
+    }
+}
+
+/// 六爻纳甲盘
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LiuYao {
+    /// 本卦
+    pub hexagram: Hexagram,
+    /// 变卦，无动爻时为 None
+    pub changed: Option<Hexagram>,
+    /// 所属八宫
+    pub palace: Trigram,
+    /// 卦宫五行，六亲以此为我
+    pub palace_element: WuXing,
+    /// 八宫世代
+    pub generation: Generation,
+    /// 世爻位置
+    pub shi: usize,
+    /// 应爻位置
+    pub ying: usize,
+    /// 月建
+    pub month: GanZhi,
+    /// 日辰
+    pub day: GanZhi,
+    /// 日空亡的两个地支
+    pub kong_wang: [EarthlyBranch; 2],
+    /// 六爻，从初爻到上爻
+    pub lines: [Line; 6],
+}
+
+impl LiuYao {
+    /// 由本卦、动爻（1为初爻，6为上爻）与月日干支排盘
+    pub fn new(hexagram: Hexagram, moving: &[usize], month: GanZhi, day: GanZhi) -> Result<Self, MingbuError> {
+        if let Some(position) = moving.iter().find(|position| !(1..=6).contains(*position)) {
+            return Err(MingbuError {
+                code: "LIU_YAO_ERROR",
+                message: format!("动爻位置 {} 超出范围 1..=6", position),
+            });
+        }
+        let (palace, generation) = palace_of(&hexagram);
+        let palace_element = palace.wuxing();
+        let changed = (!moving.is_empty()).then(|| hexagram.bian_gua(moving));
+        let kong_wang = day.kong_wang();
+        let month_branch = *month.branch();
+        let first_spirit = LiuShen::first(*day.stem()) as usize;
+
+        let na_jia = |hexagram: &Hexagram, position| NaJia::of(hexagram, position, palace_element);
+        let relatives: Vec<LiuQin> = (1..=6).map(|position| na_jia(&hexagram, position).relative).collect();
+        let pure = Hexagram::new(palace, palace);
+        let yinyang = hexagram.lines();
+
+        let lines = core::array::from_fn(|i| {
+            let position = i + 1;
+            let own = na_jia(&hexagram, position);
+            let hidden = Some(na_jia(&pure, position)).filter(|line| !relatives.contains(&line.relative));
+            let is_moving = moving.contains(&position);
+            Line {
+                position,
+                yinyang: yinyang[i],
+                moving: is_moving,
+                na_jia: own,
+                spirit: LiuShen::ALL[(first_spirit + i) % 6],
+                shi: position == generation.shi(),
+                ying: position == generation.ying(),
+                kong_wang: kong_wang.contains(&own.branch),
+                yue_po: own.branch == month_branch.chong(),
+                hidden,
+                changed: changed.filter(|_| is_moving).map(|changed| na_jia(&changed, position)),
+            }
+        });
+
+        Ok(LiuYao {
+            hexagram,
+            changed,
+            palace,
+            palace_element,
+            generation,
+            shi: generation.shi(),
+            ying: generation.ying(),
+            month,
+            day,
+            kong_wang,
+            lines,
+        })
+    }
+
+    /// 由起卦结果与月日干支排盘
+    pub fn from_cast(cast: &Cast, month: GanZhi, day: GanZhi) -> Result<Self, MingbuError> {
+        Self::new(cast.hexagram, &cast.moving, month, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gan_zhi(name: &str) -> GanZhi {
+        name.parse().unwrap()
+    }
+
+    fn hexagram(name: &str) -> Hexagram {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn test_palaces() {
+        assert_eq!(palace_of(&hexagram("姤")), (Trigram::Qian, Generation::YiShi));
+        assert_eq!(palace_of(&hexagram("晋")), (Trigram::Qian, Generation::YouHun));
+        assert_eq!(palace_of(&hexagram("大有")), (Trigram::Qian, Generation::GuiHun));
+        assert_eq!(palace_of(&hexagram("明夷")), (Trigram::Kan, Generation::YouHun));
+        assert_eq!(palace_of(&hexagram("泰")), (Trigram::Kun, Generation::SanShi));
+        assert_eq!(palace_of(&hexagram("随")), (Trigram::Zhen, Generation::GuiHun));
+        // 八宫各八卦，恰好覆盖六十四卦
+        for palace in Trigram::ALL {
+            let count = Hexagram::ALL.iter().filter(|h| palace_of(h).0 == palace).count();
+            assert_eq!(count, 8);
+        }
+    }
+
+    #[test]
+    fn test_gou_chart() {
+        // 天风姤，寅月甲子日，初爻动
+        let chart = LiuYao::new(hexagram("姤"), &[1], gan_zhi("丙寅"), gan_zhi("甲子")).unwrap();
+        assert_eq!((chart.shi, chart.ying), (1, 4));
+        assert_eq!(chart.changed, Some(hexagram("乾")));
+
+        let branches: Vec<_> = chart.lines.iter().map(|line| line.na_jia.branch.chinese_name()).collect();
+        assert_eq!(branches, ["丑", "亥", "酉", "午", "申", "戌"]);
+        let relatives: Vec<_> = chart.lines.iter().map(|line| line.na_jia.relative.chinese_name()).collect();
+        assert_eq!(relatives, ["父母", "子孙", "兄弟", "官鬼", "兄弟", "父母"]);
+
+        // 缺妻财，伏于二爻之下：乾为天二爻甲寅木
+        let hidden: Vec<_> = chart.lines.iter().filter_map(|line| line.hidden.map(|h| (line.position, h))).collect();
+        assert_eq!(hidden.len(), 1);
+        assert_eq!(hidden[0].0, 2);
+        assert_eq!((hidden[0].1.stem, hidden[0].1.branch), (HeavenlyStem::Jia, EarthlyBranch::Yin));
+        assert_eq!(hidden[0].1.relative, LiuQin::QiCai);
+
+        assert_eq!(chart.lines[0].spirit, LiuShen::QingLong);
+        assert_eq!(chart.lines[5].spirit, LiuShen::XuanWu);
+        assert!(chart.lines[1].kong_wang && chart.lines[5].kong_wang);
+        assert!(chart.lines[4].yue_po);
+        assert_eq!(chart.lines.iter().filter(|line| line.yue_po).count(), 1);
+
+        let changed = chart.lines[0].changed.unwrap();
+        assert_eq!((changed.stem, changed.branch, changed.relative), (HeavenlyStem::Jia, EarthlyBranch::Zi, LiuQin::ZiSun));
+        assert!(chart.lines[1].changed.is_none());
+    }
+
+    #[test]
+    fn test_cast() {
+        let cast = Cast::from_coins([6, 8, 8, 7, 7, 7]).unwrap();
+        assert_eq!(cast.hexagram, hexagram("否"));
+        assert_eq!(cast.moving, [1]);
+        assert!(Cast::from_coins([9, 8, 8, 7, 7, 5]).is_err());
+
+        // 上数9除8余1为乾，下数6为坎，(9+6)%6=3，三爻动
+        let cast = Cast::from_numbers(9, 6);
+        assert_eq!(cast.hexagram, hexagram("讼"));
+        assert_eq!(cast.moving, [3]);
+        assert_eq!(Cast::from_numbers(8, 4).moving, [6]);
+
+        // 子年(1)+十二月+十二日=25，余1为乾；再加卯时(4)=29，余5为巽，29%6=5
+        let cast = Cast::from_time(&LunarDate::new(2020, 12, false, 12).unwrap(), EarthlyBranch::Mao);
+        assert_eq!(cast.hexagram, hexagram("姤"));
+        assert_eq!(cast.moving, [5]);
+
+        assert!(LiuYao::new(hexagram("乾"), &[7], gan_zhi("丙寅"), gan_zhi("甲子")).is_err());
+        let chart = LiuYao::from_cast(&Cast::from_coins([7; 6]).unwrap(), gan_zhi("丙寅"), gan_zhi("甲子")).unwrap();
+        assert!(chart.changed.is_none());
+        assert!(chart.lines.iter().all(|line| line.hidden.is_none()));
+    }
+}
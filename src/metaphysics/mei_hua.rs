@@ -0,0 +1,242 @@
+// src/metaphysics/mei_hua.rs - 梅花易数
+// 由时间、数字或字的笔画起卦，得本卦、互卦、变卦，再分体用论生克与旺衰
+use alloc::format;
+use serde::Serialize;
+#[cfg(feature = "ephemeris")]
+use crate::calendar::ephemeris::EphemerisError;
+#[cfg(feature = "ephemeris")]
+use crate::calendar::lunar;
+use crate::calendar::lunar::LunarDate;
+use crate::concepts::bagua::Trigram;
+use crate::concepts::gan_zhi::EarthlyBranch;
+use crate::concepts::hexagram::Hexagram;
+use crate::concepts::traits::{ChineseName, WuXingTrait};
+use crate::concepts::wu_xing::{WangXiang, WuXing};
+use crate::json::MingbuError;
+use crate::metaphysics::liu_yao::Cast;
+
+/// 体卦与用卦的五行生克关系
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TiYong {
+    /// 体用比和
+    BiHe,
+    /// 用生体
+    YongShengTi,
+    /// 体克用
+    TiKeYong,
+    /// 体生用
+    TiShengYong,
+    /// 用克体
+    YongKeTi,
+}
+
+impl ChineseName for TiYong {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            TiYong::BiHe => "比和",
+            TiYong::YongShengTi => "用生体",
+            TiYong::TiKeYong => "体克用",
+            TiYong::TiShengYong => "体生用",
+            TiYong::YongKeTi => "用克体",
+        }
+    }
+}
+
+impl TiYong {
+    /// 由体用五行求二者关系
+    pub fn of(ti: WuXing, yong: WuXing) -> Self {
+        if ti == yong {
+            TiYong::BiHe
+        } else if yong.generates() == ti {
+            TiYong::YongShengTi
+        } else if ti.overcomes() == yong {
+            TiYong::TiKeYong
+        } else if ti.generates() == yong {
+            TiYong::TiShengYong
+        } else {
+            TiYong::YongKeTi
+        }
+    }
+
+    /// 是否为吉：用生体、比和、体克用为吉，体生用、用克体为不吉
+    pub fn is_favorable(&self) -> bool {
+        matches!(self, TiYong::BiHe | TiYong::YongShengTi | TiYong::TiKeYong)
+    }
+}
+
+/// 梅花易数卦盘
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MeiHua {
+    /// 本卦
+    pub original: Hexagram,
+    /// 互卦
+    pub mutual: Hexagram,
+    /// 变卦
+    pub changed: Hexagram,
+    /// 动爻位置，1为初爻，6为上爻
+    pub moving: usize,
+    /// 体卦：不含动爻的经卦
+    pub ti: Trigram,
+    /// 用卦：含动爻的经卦
+    pub yong: Trigram,
+    /// 体用生克
+    pub relation: TiYong,
+    /// 起卦所在的月支，用于论旺衰
+    pub month: EarthlyBranch,
+    /// 体卦在月令中的旺相休囚死
+    pub ti_strength: WangXiang,
+    /// 用卦在月令中的旺相休囚死
+    pub yong_strength: WangXiang,
+}
+
+impl MeiHua {
+    /// 由本卦、动爻（1为初爻，6为上爻）与月支排盘
+    pub fn new(original: Hexagram, moving: usize, month: EarthlyBranch) -> Result<Self, MingbuError> {
+        if !(1..=6).contains(&moving) {
+            return Err(MingbuError {
+                code: "MEI_HUA_ERROR",
+                message: format!("动爻位置 {} 超出范围 1..=6", moving),
+            });
+        }
+        // 动爻所在的经卦为用，另一经卦为体
+        let (ti, yong) = if moving > 3 {
+            (original.lower, original.upper)
+        } else {
+            (original.upper, original.lower)
+        };
+        let ruling = month.wuxing();
+        Ok(MeiHua {
+            original,
+            mutual: original.hu_gua(),
+            changed: original.bian_gua(&[moving]),
+            moving,
+            ti,
+            yong,
+            relation: TiYong::of(ti.wuxing(), yong.wuxing()),
+            month,
+            ti_strength: ti.wuxing().wang_xiang(ruling),
+            yong_strength: yong.wuxing().wang_xiang(ruling),
+        })
+    }
+
+    /// 年月日时起卦：年支数、农历月、日之和除八为上卦，再加时支数除八为下卦，总数除六为动爻
+    /// 月令取农历月所建之支（正月建寅）
+    pub fn from_time(date: &LunarDate, hour: EarthlyBranch) -> Self {
+        let cast = Cast::from_time(date, hour);
+        Self::new(cast.hexagram, cast.moving[0], date.month_branch()).unwrap()
+    }
+
+    /// 以世界时儒略日起卦：按北京时间换算农历日期与时辰后年月日时起卦
+    #[cfg(feature = "ephemeris")]
+    pub fn from_julian_day(julian_day: f64) -> Result<Self, EphemerisError> {
+        let date = LunarDate::from_julian_day(julian_day)?;
+        Ok(Self::from_time(&date, lunar::hour_branch(julian_day)))
+    }
+
+    /// 两数起卦：第一数除八为上卦，第二数除八为下卦，两数之和除六为动爻
+    pub fn from_numbers(first: u32, second: u32, month: EarthlyBranch) -> Self {
+        let cast = Cast::from_numbers(first, second);
+        Self::new(cast.hexagram, cast.moving[0], month).unwrap()
+    }
+
+    /// 三数起卦：第一数除八为上卦，第二数除八为下卦，三数之和除六为动爻
+    pub fn from_three_numbers(first: u32, second: u32, third: u32, month: EarthlyBranch) -> Self {
+        let (first, second, third) = (first as u64, second as u64, third as u64);
+        Self::from_sums(first, second, first + second + third, month)
+    }
+
+    /// 字占：按各字笔画起卦，字数为偶时平分，为奇时上卦少取一字
+    /// 上半笔画之和除八为上卦，下半除八为下卦，总笔画除六为动爻
+    /// 至少需要两个字；一字占可将左右两半的笔画作为两字传入
+    pub fn from_strokes(strokes: &[u32], month: EarthlyBranch) -> Result<Self, MingbuError> {
+        if strokes.len() < 2 {
+            return Err(MingbuError {
+                code: "MEI_HUA_ERROR",
+                message: format!("字占至少需要两个笔画数，实际为 {} 个", strokes.len()),
+            });
+        }
+        let (upper, lower) = strokes.split_at(strokes.len() / 2);
+        let sum = |part: &[u32]| part.iter().map(|n| *n as u64).sum::<u64>();
+        Ok(Self::from_sums(sum(upper), sum(lower), sum(strokes), month))
+    }
+
+    fn from_sums(upper: u64, lower: u64, moving: u64, month: EarthlyBranch) -> Self {
+        let cast = Cast::from_sums(upper, lower, moving);
+        Self::new(cast.hexagram, cast.moving[0], month).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hexagram(name: &str) -> Hexagram {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_time() {
+        // 《梅花易数》观梅占：辰年十二月十七日申时，得泽火革，初爻动
+        let date = LunarDate::new(2000, 12, false, 17).unwrap();
+        assert_eq!(date.year_branch(), EarthlyBranch::Chen);
+        let reading = MeiHua::from_time(&date, EarthlyBranch::Shen);
+        assert_eq!(reading.original, hexagram("革"));
+        assert_eq!(reading.moving, 1);
+        assert_eq!(reading.mutual, hexagram("姤"));
+        assert_eq!(reading.changed, hexagram("咸"));
+        assert_eq!((reading.ti, reading.yong), (Trigram::Dui, Trigram::Li));
+        assert_eq!(reading.relation, TiYong::YongKeTi);
+        assert!(!reading.relation.is_favorable());
+        // 十二月建丑，土旺：金为相，火为休
+        assert_eq!(reading.month, EarthlyBranch::Chou);
+        assert_eq!(reading.ti_strength, WangXiang::Xiang);
+        assert_eq!(reading.yong_strength, WangXiang::Xiu);
+    }
+
+    #[cfg(feature = "ephemeris")]
+    #[test]
+    fn test_from_julian_day() {
+        // 2024年2月10日北京时间正午：甲辰年正月初一午时，辰5+1+1=7为艮，再加午7得14为坎，14%6动二爻
+        let reading = MeiHua::from_julian_day(2460350.5 + 4.0 / 24.0).unwrap();
+        assert_eq!(reading.original, hexagram("蒙"));
+        assert_eq!(reading.moving, 2);
+        assert_eq!(reading.month, EarthlyBranch::Yin);
+    }
+
+    #[test]
+    fn test_from_numbers() {
+        let reading = MeiHua::from_numbers(9, 6, EarthlyBranch::Yin);
+        assert_eq!(reading.original, hexagram("讼"));
+        assert_eq!(reading.moving, 3);
+        assert_eq!((reading.ti, reading.yong), (Trigram::Qian, Trigram::Kan));
+        assert_eq!(reading.relation, TiYong::TiShengYong);
+
+        let reading = MeiHua::from_three_numbers(3, 5, 4, EarthlyBranch::Wu);
+        assert_eq!(reading.original, Hexagram::new(Trigram::Li, Trigram::Xun));
+        assert_eq!(reading.moving, 6);
+        assert_eq!(reading.ti, Trigram::Xun);
+        assert_eq!(reading.relation, TiYong::TiShengYong);
+        assert_eq!(reading.ti_strength, WangXiang::Xiu);
+    }
+
+    #[test]
+    fn test_from_strokes() {
+        // 三字：上取一字（5画），下取两字（7+9画），总21画动三爻
+        let reading = MeiHua::from_strokes(&[5, 7, 9], EarthlyBranch::You).unwrap();
+        assert_eq!(reading.original, Hexagram::new(Trigram::Xun, Trigram::Kun));
+        assert_eq!(reading.moving, 3);
+        assert!(MeiHua::from_strokes(&[5], EarthlyBranch::You).is_err());
+        assert!(MeiHua::new(hexagram("乾"), 0, EarthlyBranch::Zi).is_err());
+    }
+
+    #[test]
+    fn test_relation() {
+        assert_eq!(TiYong::of(WuXing::Wood, WuXing::Water), TiYong::YongShengTi);
+        assert_eq!(TiYong::of(WuXing::Wood, WuXing::Earth), TiYong::TiKeYong);
+        assert_eq!(TiYong::of(WuXing::Wood, WuXing::Wood), TiYong::BiHe);
+        assert_eq!(TiYong::of(WuXing::Wood, WuXing::Metal), TiYong::YongKeTi);
+        assert_eq!(WuXing::Water.wang_xiang(WuXing::Metal), WangXiang::Xiang);
+        assert_eq!(WuXing::Wood.wang_xiang(WuXing::Metal), WangXiang::Si);
+        assert_eq!(WuXing::Fire.wang_xiang(WuXing::Metal), WangXiang::Qiu);
+    }
+}
@@ -1,42 +1,1336 @@
-// src/metaphysics/qi_zheng.rs 
-// This needs to include the QiZhengPan struct that was referenced in src/metaphysics.rs
+// src/metaphysics/qi_zheng.rs - 七政四余
+// 七政（日、月、五星）与罗睺、计都、月孛的位置经 calendar::ephemeris 由 Swiss Ephemeris 计算，
+// 紫气按二十八年一周天的平行模型推算；各曜按黄道宿度求所入之宿与十二次，按命宫排十二宫，
+// 并由日行度与距日角距经 Calculate 判断顺逆留伏迟疾，按 calendar::aspect 求各曜之间及本命与流年之间的相位，
+// 按十干化曜表标注各曜在本命年干（以立春为岁首）与流年年干下的化曜
+use alloc::format;
+use alloc::vec::Vec;
 use serde::Serialize;
+use crate::calendar::aspect::{self, Aspect, AspectPoint, AspectSet};
+use crate::calendar::calculate::{self, Calculate};
+use crate::calendar::ephemeris::{self, EphemerisError};
+use crate::calendar::jie_qi;
+use crate::calendar::search::SearchOptions;
+use crate::calendar::swisseph::{
+    SwissEph, SE_SUN, SE_MOON, SE_MERCURY, SE_VENUS, SE_MARS, SE_JUPITER, SE_SATURN,
+    SE_MEAN_NODE, SE_TRUE_NODE, SE_MEAN_APOG, SE_OSCU_APOG,
+};
+use crate::concepts::ci::{CiBoundary, CiPlacement, CiStation};
+use crate::concepts::gan_zhi::{EarthlyBranch, HeavenlyStem};
+use crate::concepts::traits::{ChineseName, Index, WuXingTrait};
+use crate::concepts::wu_xing::WuXing;
+use crate::concepts::xiu::{MansionFrame, MansionPlacement, MansionTable, XiuMansion};
+use crate::json::MingbuError;
+
+/// 七政：日、月与金木水火土五星
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Luminary {
+    /// 太阳
+    Sun,
+    /// 太阴
+    Moon,
+    /// 水星
+    Mercury,
+    /// 金星
+    Venus,
+    /// 火星
+    Mars,
+    /// 木星
+    Jupiter,
+    /// 土星
+    Saturn,
+}
+
+impl ChineseName for Luminary {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Luminary::Sun => "太阳",
+            Luminary::Moon => "太阴",
+            Luminary::Mercury => "水星",
+            Luminary::Venus => "金星",
+            Luminary::Mars => "火星",
+            Luminary::Jupiter => "木星",
+            Luminary::Saturn => "土星",
+        }
+    }
+}
+
+impl WuXingTrait for Luminary {
+    /// 日为火之精、月为水之精，五星各从其名
+    fn wuxing(&self) -> WuXing {
+        match self {
+            Luminary::Sun | Luminary::Mars => WuXing::Fire,
+            Luminary::Moon | Luminary::Mercury => WuXing::Water,
+            Luminary::Venus => WuXing::Metal,
+            Luminary::Jupiter => WuXing::Wood,
+            Luminary::Saturn => WuXing::Earth,
+        }
+    }
+}
+
+impl Luminary {
+    /// 七政，按日、月、水、金、火、木、土排列
+    pub const ALL: [Luminary; 7] = [
+        Luminary::Sun, Luminary::Moon, Luminary::Mercury, Luminary::Venus,
+        Luminary::Mars, Luminary::Jupiter, Luminary::Saturn,
+    ];
+
+    /// 古名：日、月、辰星、太白、荧惑、岁星、镇星
+    pub fn ancient_name(&self) -> &'static str {
+        match self {
+            Luminary::Sun => "日",
+            Luminary::Moon => "月",
+            Luminary::Mercury => "辰星",
+            Luminary::Venus => "太白",
+            Luminary::Mars => "荧惑",
+            Luminary::Jupiter => "岁星",
+            Luminary::Saturn => "镇星",
+        }
+    }
+
+    /// Swiss Ephemeris 中的天体编号
+    pub fn body(&self) -> i32 {
+        match self {
+            Luminary::Sun => SE_SUN,
+            Luminary::Moon => SE_MOON,
+            Luminary::Mercury => SE_MERCURY,
+            Luminary::Venus => SE_VENUS,
+            Luminary::Mars => SE_MARS,
+            Luminary::Jupiter => SE_JUPITER,
+            Luminary::Saturn => SE_SATURN,
+        }
+    }
+}
+
+/// 七政在回归黄道上的位置
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Position {
+    /// 黄经（度）
+    pub longitude: f64,
+    /// 黄纬（度）
+    pub latitude: f64,
+    /// 黄经速度（度/日）
+    pub speed: f64,
+    /// 是否逆行
+    pub retrograde: bool,
+}
+
+impl Position {
+    /// 计算某一政在世界时儒略日的位置
+    pub fn of(luminary: Luminary, julian_day: f64) -> Result<Self, EphemerisError> {
+        Self::of_body(&ephemeris::open()?, luminary.body(), julian_day)
+    }
+
+    fn of_body(eph: &SwissEph, body: i32, julian_day: f64) -> Result<Self, EphemerisError> {
+        let position = ephemeris::body_position(eph, julian_day, body)?;
+        Ok(Position {
+            longitude: position.longitude,
+            latitude: position.latitude,
+            speed: position.longitude_speed,
+            retrograde: position.longitude_speed < 0.0,
+        })
+    }
+
+    /// 对冲点：黄经加180°，黄纬反号，速度不变
+    pub fn opposite(&self) -> Self {
+        Position {
+            longitude: (self.longitude + 180.0) % 360.0,
+            latitude: -self.latitude,
+            ..*self
+        }
+    }
+}
+
+/// 四余：罗睺、计都、月孛、紫气
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Remainder {
+    /// 罗睺
+    LuoHou,
+    /// 计都
+    JiDu,
+    /// 月孛
+    YueBei,
+    /// 紫气
+    ZiQi,
+}
+
+impl ChineseName for Remainder {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Remainder::LuoHou => "罗睺",
+            Remainder::JiDu => "计都",
+            Remainder::YueBei => "月孛",
+            Remainder::ZiQi => "紫气",
+        }
+    }
+}
+
+impl WuXingTrait for Remainder {
+    /// 罗睺为火之余、计都为土之余、月孛为水之余、紫气为木之余
+    fn wuxing(&self) -> WuXing {
+        match self {
+            Remainder::LuoHou => WuXing::Fire,
+            Remainder::JiDu => WuXing::Earth,
+            Remainder::YueBei => WuXing::Water,
+            Remainder::ZiQi => WuXing::Wood,
+        }
+    }
+}
+
+impl Remainder {
+    /// 四余，按罗、计、孛、气排列
+    pub const ALL: [Remainder; 4] = [Remainder::LuoHou, Remainder::JiDu, Remainder::YueBei, Remainder::ZiQi];
+}
+
+/// 黄白交点的取法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum NodeModel {
+    /// 平交点
+    #[default]
+    Mean,
+    /// 真交点
+    True,
+}
+
+/// 罗睺所取的交点，计都取其对冲
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum LuoHouNode {
+    /// 罗睺为升交点（与印度 Rahu 同）
+    #[default]
+    Ascending,
+    /// 罗睺为降交点，即罗计互换之说
+    Descending,
+}
+
+/// 月孛所取的月球远地点
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum ApogeeModel {
+    /// 平远地点
+    #[default]
+    Mean,
+    /// 密切远地点
+    Osculating,
+}
+
+/// 紫气的平行模型：自历元起匀速顺行，period_years 年一周天
+/// 各家起例不同，历元与历元时的黄经须按所宗之法给出，不设默认值
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ZiQiModel {
+    /// 历元（世界时儒略日）
+    pub epoch: f64,
+    /// 历元时紫气的黄经（度）
+    pub longitude: f64,
+    /// 一周天的回归年数
+    pub period_years: f64,
+}
+
+impl ZiQiModel {
+    /// 回归年日数
+    pub const TROPICAL_YEAR: f64 = 365.2422;
+
+    /// 紫气一周天的年数
+    pub const PERIOD_YEARS: f64 = 28.0;
+
+    /// 由起例的历元（世界时儒略日）与该时紫气黄经建立模型，二十八年一周天
+    pub fn new(epoch: f64, longitude: f64) -> Self {
+        ZiQiModel { epoch, longitude, period_years: Self::PERIOD_YEARS }
+    }
+
+    /// 每日行度
+    pub fn speed(&self) -> f64 {
+        360.0 / (self.period_years * Self::TROPICAL_YEAR)
+    }
+
+    /// 世界时儒略日的紫气位置
+    pub fn position(&self, julian_day: f64) -> Position {
+        let speed = self.speed();
+        let longitude = self.longitude + speed * (julian_day - self.epoch);
+        Position {
+            longitude: (longitude % 360.0 + 360.0) % 360.0,
+            latitude: 0.0,
+            speed,
+            retrograde: false,
+        }
+    }
+}
+
+/// 四余的取法，随盘输出以注明所用定义
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RemainderOptions {
+    /// 罗睺、计都所用的交点
+    pub node: NodeModel,
+    /// 罗睺取升交点或降交点
+    pub luo_hou: LuoHouNode,
+    /// 月孛所用的远地点
+    pub apogee: ApogeeModel,
+    /// 紫气的平行模型
+    pub zi_qi: ZiQiModel,
+}
+
+impl RemainderOptions {
+    /// 以给定的紫气起例建立取法，罗计取平升交点为罗睺，月孛取平远地点
+    pub fn new(zi_qi: ZiQiModel) -> Self {
+        RemainderOptions {
+            node: NodeModel::default(),
+            luo_hou: LuoHouNode::default(),
+            apogee: ApogeeModel::default(),
+            zi_qi,
+        }
+    }
+
+    /// 计算罗睺、计都、月孛、紫气的位置
+    pub fn positions(&self, julian_day: f64) -> Result<[Position; 4], EphemerisError> {
+        self.positions_in(&ephemeris::open()?, julian_day)
+    }
+
+    fn positions_in(&self, eph: &SwissEph, julian_day: f64) -> Result<[Position; 4], EphemerisError> {
+        let body = match self.node {
+            NodeModel::Mean => SE_MEAN_NODE,
+            NodeModel::True => SE_TRUE_NODE,
+        };
+        let ascending = Position::of_body(eph, body, julian_day)?;
+        let descending = ascending.opposite();
+        let (luo_hou, ji_du) = match self.luo_hou {
+            LuoHouNode::Ascending => (ascending, descending),
+            LuoHouNode::Descending => (descending, ascending),
+        };
+        let apogee = match self.apogee {
+            ApogeeModel::Mean => SE_MEAN_APOG,
+            ApogeeModel::Osculating => SE_OSCU_APOG,
+        };
+        let yue_bei = Position::of_body(eph, apogee, julian_day)?;
+        Ok([luo_hou, ji_du, yue_bei, self.zi_qi.position(julian_day)])
+    }
+}
+
+/// 七政四余中的一曜
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Body {
+    /// 七政
+    Luminary(Luminary),
+    /// 四余
+    Remainder(Remainder),
+}
+
+impl ChineseName for Body {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Body::Luminary(luminary) => luminary.chinese_name(),
+            Body::Remainder(remainder) => remainder.chinese_name(),
+        }
+    }
+}
+
+impl WuXingTrait for Body {
+    fn wuxing(&self) -> WuXing {
+        match self {
+            Body::Luminary(luminary) => luminary.wuxing(),
+            Body::Remainder(remainder) => remainder.wuxing(),
+        }
+    }
+}
+
+impl Body {
+    /// 七政四余十一曜，七政在前、四余在后
+    pub const ALL: [Body; 11] = [
+        Body::Luminary(Luminary::Sun), Body::Luminary(Luminary::Moon), Body::Luminary(Luminary::Mercury),
+        Body::Luminary(Luminary::Venus), Body::Luminary(Luminary::Mars), Body::Luminary(Luminary::Jupiter),
+        Body::Luminary(Luminary::Saturn), Body::Remainder(Remainder::LuoHou), Body::Remainder(Remainder::JiDu),
+        Body::Remainder(Remainder::YueBei), Body::Remainder(Remainder::ZiQi),
+    ];
+
+    /// 星家简称：日、月、水、金、火、木、土、罗、计、孛、气
+    pub fn short_name(&self) -> char {
+        match self {
+            Body::Luminary(Luminary::Sun) => '日',
+            Body::Luminary(Luminary::Moon) => '月',
+            Body::Luminary(Luminary::Mercury) => '水',
+            Body::Luminary(Luminary::Venus) => '金',
+            Body::Luminary(Luminary::Mars) => '火',
+            Body::Luminary(Luminary::Jupiter) => '木',
+            Body::Luminary(Luminary::Saturn) => '土',
+            Body::Remainder(Remainder::LuoHou) => '罗',
+            Body::Remainder(Remainder::JiDu) => '计',
+            Body::Remainder(Remainder::YueBei) => '孛',
+            Body::Remainder(Remainder::ZiQi) => '气',
+        }
+    }
+
+    /// 由简称求曜，紫气亦作“炁”
+    pub fn from_short_name(name: char) -> Option<Self> {
+        let name = if name == '炁' { '气' } else { name };
+        Self::ALL.into_iter().find(|body| body.short_name() == name)
+    }
+
+    /// 世界时儒略日的位置，四余按给定取法
+    pub fn position(&self, julian_day: f64, remainders: &RemainderOptions) -> Result<Position, EphemerisError> {
+        self.position_in(&ephemeris::open()?, julian_day, remainders)
+    }
+
+    fn position_in(&self, eph: &SwissEph, julian_day: f64, remainders: &RemainderOptions) -> Result<Position, EphemerisError> {
+        match self {
+            Body::Luminary(luminary) => Position::of_body(eph, luminary.body(), julian_day),
+            Body::Remainder(remainder) => Ok(remainders.positions_in(eph, julian_day)?[*remainder as usize]),
+        }
+    }
+}
+
+/// 十干化曜的十种角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum HuaYao {
+    /// 化禄
+    Lu,
+    /// 化暗
+    An,
+    /// 化福
+    Fu,
+    /// 化耗
+    Hao,
+    /// 化荫
+    YinBi,
+    /// 化贵
+    Gui,
+    /// 化刑
+    Xing,
+    /// 化印
+    Yin,
+    /// 化囚
+    Qiu,
+    /// 化权
+    Quan,
+}
+
+impl ChineseName for HuaYao {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            HuaYao::Lu => "化禄",
+            HuaYao::An => "化暗",
+            HuaYao::Fu => "化福",
+            HuaYao::Hao => "化耗",
+            HuaYao::YinBi => "化荫",
+            HuaYao::Gui => "化贵",
+            HuaYao::Xing => "化刑",
+            HuaYao::Yin => "化印",
+            HuaYao::Qiu => "化囚",
+            HuaYao::Quan => "化权",
+        }
+    }
+}
+
+impl HuaYao {
+    /// 十种化曜，按禄、暗、福、耗、荫、贵、刑、印、囚、权排列
+    pub const ALL: [HuaYao; 10] = [
+        HuaYao::Lu, HuaYao::An, HuaYao::Fu, HuaYao::Hao, HuaYao::YinBi,
+        HuaYao::Gui, HuaYao::Xing, HuaYao::Yin, HuaYao::Qiu, HuaYao::Quan,
+    ];
+}
+
+/// 十干化曜表：每一年干下禄、暗、福、耗、荫、贵、刑、印、囚、权各化何曜
+/// 各家所传之表互有出入，附带《张果星宗》之表，其余各家可经 from_rows 按所宗之书录入
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct HuaYaoTable {
+    /// 按甲至癸排列，每行按 HuaYao::ALL 的次序
+    pub rows: [[Body; 10]; 10],
+}
+
+impl HuaYaoTable {
+    /// 由十行简称建表，按甲至癸排列，每行十字依次为禄、暗、福、耗、荫、贵、刑、印、囚、权所化之曜
+    /// 如“火孛木金土月水气计罗”；字间的空白与顿号忽略，紫气可作“炁”
+    pub fn from_rows(rows: [&str; 10]) -> Result<Self, MingbuError> {
+        let error = |message: String| MingbuError { code: "QI_ZHENG_ERROR", message };
+        let mut table = [[Body::Luminary(Luminary::Sun); 10]; 10];
+        for (stem, (row, text)) in HeavenlyStem::ALL.iter().zip(table.iter_mut().zip(rows)) {
+            let names: Vec<char> = text.chars().filter(|c| !c.is_whitespace() && *c != '、').collect();
+            if names.len() != 10 {
+                return Err(error(format!("{}干化曜应为十曜: {}", stem.chinese_name(), text)));
+            }
+            for (slot, name) in row.iter_mut().zip(names) {
+                *slot = Body::from_short_name(name).ok_or_else(|| error(format!("无法识别的曜: {}", name)))?;
+            }
+        }
+        Ok(HuaYaoTable { rows: table })
+    }
+
+    /// 《张果星宗》十干化曜：甲干依次化火、孛、木、金、土、月、水、炁、计、罗，
+    /// 以后每干将首曜移至末尾，太阳不化
+    pub fn guo_lao() -> Self {
+        Self::from_rows([
+            "火孛木金土月水炁计罗",
+            "孛木金土月水炁计罗火",
+            "木金土月水炁计罗火孛",
+            "金土月水炁计罗火孛木",
+            "土月水炁计罗火孛木金",
+            "月水炁计罗火孛木金土",
+            "水炁计罗火孛木金土月",
+            "炁计罗火孛木金土月水",
+            "计罗火孛木金土月水炁",
+            "罗火孛木金土月水炁计",
+        ])
+        .expect("果老化曜表的简称都可识别")
+    }
+
+    /// 某年干下某一角色所化之曜
+    pub fn body(&self, stem: HeavenlyStem, role: HuaYao) -> Body {
+        self.rows[stem.index() - 1][role as usize]
+    }
+
+    /// 某年干下某曜所得的各个角色，未化者为空
+    pub fn roles(&self, stem: HeavenlyStem, body: Body) -> Vec<HuaYao> {
+        HuaYao::ALL.into_iter().filter(|role| self.body(stem, *role) == body).collect()
+    }
+}
+
+/// 流年：直接给出年干，或给出流年中的世界时儒略日，以立春为岁首定年干
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FlowYear {
+    /// 流年年干
+    Stem(HeavenlyStem),
+    /// 流年中的世界时儒略日
+    JulianDay(f64),
+}
+
+impl FlowYear {
+    /// 流年年干
+    pub fn stem(&self) -> Result<HeavenlyStem, EphemerisError> {
+        match self {
+            FlowYear::Stem(stem) => Ok(*stem),
+            FlowYear::JulianDay(julian_day) => Ok(*jie_qi::year_gan_zhi(*julian_day)?.stem()),
+        }
+    }
+}
+
+/// 一曜的化曜
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BodyHuaYao {
+    /// 七政或四余
+    pub body: Body,
+    /// 本命年干所化
+    pub natal: Vec<HuaYao>,
+    /// 流年年干所化，未给流年时为空
+    pub flow: Vec<HuaYao>,
+}
+
+/// 宿度的划分方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum MansionBoundary {
+    /// 以各宿距星在历元时的实测位置为界
+    #[default]
+    Modern,
+    /// 以角宿距星为起点，按古度宿度依次累加
+    Ancient,
+}
+
+/// 入宿所用的宿度表
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub struct MansionOptions {
+    /// 宿度的划分方式
+    pub boundary: MansionBoundary,
+    /// 取距星位置的历元（世界时儒略日），None 为排盘时刻
+    pub epoch: Option<f64>,
+}
+
+/// 以各宿距星在世界时儒略日的位置建宿度表，古度表仅取角宿距星为起点
+/// 距星位置由 ephe/sefstars.txt 经 swe_fixstar2 计算
+pub fn mansion_table(frame: MansionFrame, boundary: MansionBoundary, julian_day: f64) -> Result<MansionTable, EphemerisError> {
+    mansion_table_in(&ephemeris::open()?, frame, boundary, julian_day)
+}
+
+fn mansion_table_in(eph: &SwissEph, frame: MansionFrame, boundary: MansionBoundary, julian_day: f64) -> Result<MansionTable, EphemerisError> {
+    let equatorial = frame == MansionFrame::Equatorial;
+    let start = |mansion: XiuMansion| {
+        ephemeris::fixed_star_position(eph, julian_day, mansion.determinative_star(), equatorial)
+            .map(|(longitude, _)| longitude)
+    };
+    match boundary {
+        MansionBoundary::Ancient => Ok(MansionTable::ancient(frame, start(XiuMansion::JiaoMuJiao)?)),
+        MansionBoundary::Modern => {
+            let mut starts = [0.0; 28];
+            for (slot, mansion) in starts.iter_mut().zip(XiuMansion::ALL) {
+                *slot = start(mansion)?;
+            }
+            Ok(MansionTable::from_starts(frame, starts))
+        }
+    }
+}
+
+/// 十二次所用的黄道
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum Zodiac {
+    /// 回归黄道，以春分点为准
+    #[default]
+    Tropical,
+    /// 恒星黄道，参数为 Swiss Ephemeris 的岁差模式（SE_SIDM_*）
+    Sidereal(i32),
+}
+
+/// 十二次的取法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct CiOptions {
+    /// 回归黄道或恒星黄道
+    pub zodiac: Zodiac,
+    /// 以中气或节气为次首
+    pub boundary: CiBoundary,
+}
+
+impl CiOptions {
+    /// 回归黄经换算到所用黄道应减去的度数，回归黄道为0
+    pub fn offset(&self, julian_day: f64) -> Result<f64, EphemerisError> {
+        self.offset_in(&ephemeris::open()?, julian_day)
+    }
+
+    fn offset_in(&self, eph: &SwissEph, julian_day: f64) -> Result<f64, EphemerisError> {
+        match self.zodiac {
+            Zodiac::Tropical => Ok(0.0),
+            Zodiac::Sidereal(mode) => ephemeris::ayanamsa(eph, julian_day, mode),
+        }
+    }
+}
+
+/// 世界时儒略日某一回归黄经所在之次，恒星黄道经 SwissEph::set_sidereal_mode 求岁差对齐
+pub fn ci_station(longitude: f64, julian_day: f64, options: &CiOptions) -> Result<CiPlacement, EphemerisError> {
+    Ok(CiStation::from_longitude(longitude - options.offset(julian_day)?, options.boundary))
+}
+
+/// 黄经所在的地支宫：戌宫起春分点（0°），随黄经增大依次为酉、申……亥宫
+pub fn branch_of(longitude: f64) -> EarthlyBranch {
+    let sign = ((longitude.rem_euclid(360.0) / 30.0) as usize).min(11);
+    EarthlyBranch::from_index(11 + 12 - sign)
+}
+
+/// 十二宫，自命宫起按黄经增大的方向（地支逆行）依次排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Palace {
+    /// 命宫
+    Ming,
+    /// 财帛宫
+    CaiBo,
+    /// 兄弟宫
+    XiongDi,
+    /// 田宅宫
+    TianZhai,
+    /// 男女宫
+    NanNv,
+    /// 奴仆宫
+    NuPu,
+    /// 妻妾宫
+    QiQie,
+    /// 疾厄宫
+    JiE,
+    /// 迁移宫
+    QianYi,
+    /// 官禄宫
+    GuanLu,
+    /// 福德宫
+    FuDe,
+    /// 相貌宫
+    XiangMao,
+}
+
+impl ChineseName for Palace {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Palace::Ming => "命宫",
+            Palace::CaiBo => "财帛",
+            Palace::XiongDi => "兄弟",
+            Palace::TianZhai => "田宅",
+            Palace::NanNv => "男女",
+            Palace::NuPu => "奴仆",
+            Palace::QiQie => "妻妾",
+            Palace::JiE => "疾厄",
+            Palace::QianYi => "迁移",
+            Palace::GuanLu => "官禄",
+            Palace::FuDe => "福德",
+            Palace::XiangMao => "相貌",
+        }
+    }
+}
+
+impl Palace {
+    /// 十二宫，自命宫至相貌
+    pub const ALL: [Palace; 12] = [
+        Palace::Ming, Palace::CaiBo, Palace::XiongDi, Palace::TianZhai, Palace::NanNv, Palace::NuPu,
+        Palace::QiQie, Palace::JiE, Palace::QianYi, Palace::GuanLu, Palace::FuDe, Palace::XiangMao,
+    ];
+
+    /// 命宫在 ming 时，地支宫 branch 所当之宫
+    pub fn at(ming: EarthlyBranch, branch: EarthlyBranch) -> Self {
+        Self::ALL[(ming.index() + 12 - branch.index()) % 12]
+    }
+
+    /// 命宫在 ming 时，本宫所在的地支宫
+    pub fn branch(&self, ming: EarthlyBranch) -> EarthlyBranch {
+        EarthlyBranch::from_index(ming.index() + 12 - *self as usize)
+    }
+}
+
+/// 命宫的定法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum MingMethod {
+    /// 以出生地的上升点为命度
+    #[default]
+    Ascendant,
+    /// 太阳加时逆数至卯：卯时太阳所在即命宫，每迟一时辰命宫顺黄道移一宫
+    /// 命度取太阳黄经移过相应宫数，时辰按地方平太阳时
+    Sunrise,
+}
+
+/// 出生地
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Location {
+    /// 经度，东经为正
+    pub longitude: f64,
+    /// 纬度，北纬为正
+    pub latitude: f64,
+}
+
+/// 行度状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MotionState {
+    /// 顺行，行度平常
+    Normal,
+    /// 逆行
+    Retrograde,
+    /// 留：日行度近于0，将转顺逆
+    Stationary,
+    /// 伏：近日不见
+    Invisible,
+    /// 顺行而迟于平行
+    Slow,
+    /// 顺行而疾于平行
+    Fast,
+}
+
+impl ChineseName for MotionState {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            MotionState::Normal => "顺",
+            MotionState::Retrograde => "逆",
+            MotionState::Stationary => "留",
+            MotionState::Invisible => "伏",
+            MotionState::Slow => "迟",
+            MotionState::Fast => "疾",
+        }
+    }
+}
+
+impl MotionState {
+    /// 由 Calculate::get_speed_state 的结果换算
+    pub fn from_speed_state(calc: &Calculate, state: i32) -> Self {
+        match state {
+            s if s == calc.speed_reverse => MotionState::Retrograde,
+            s if s == calc.speed_stationary => MotionState::Stationary,
+            s if s == calc.speed_invisible => MotionState::Invisible,
+            s if s == calc.speed_slow => MotionState::Slow,
+            s if s == calc.speed_fast => MotionState::Fast,
+            _ => MotionState::Normal,
+        }
+    }
+}
+
+/// 顺逆留伏迟疾的判断范围，按日、月、水、金、火、木、土排列，0 表示不作此判断；四余只分顺逆
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MotionOptions {
+    /// 日行度绝对值小于此值为留（度/日）
+    pub stationary_gap: [f64; 7],
+    /// 距日角距小于此值为伏（度）
+    pub invisible_gap: [f64; 7],
+    /// 顺行而日行度低于此值为迟（度/日）
+    pub slow_speed: [f64; 7],
+    /// 顺行而日行度高于此值为疾（度/日）
+    pub fast_speed: [f64; 7],
+}
+
+impl Default for MotionOptions {
+    /// 留取各星转向前后数日的行度，伏取《授时历》伏见度，迟疾按 calculate::SPEED_RATIO 由平行增减
+    fn default() -> Self {
+        MotionOptions {
+            stationary_gap: calculate::STATIONARY_GAP,
+            invisible_gap: calculate::INVISIBLE_GAP,
+            slow_speed: calculate::SLOW_SPEED,
+            fast_speed: calculate::FAST_SPEED,
+        }
+    }
+}
+
+impl MotionOptions {
+    /// 按本选项设定判断范围的 Calculate
+    pub fn calculate(&self) -> Calculate {
+        let mut calc = Calculate::new();
+        for luminary in Luminary::ALL {
+            let i = luminary.body() as usize;
+            calc.set_stationary_gap(luminary.body(), self.stationary_gap[i]);
+            calc.set_invisible_gap(luminary.body(), self.invisible_gap[i]);
+            calc.set_slow_speed(luminary.body(), self.slow_speed[i]);
+            calc.set_fast_speed(luminary.body(), self.fast_speed[i]);
+        }
+        calc
+    }
+}
+
+/// 排盘选项，随盘输出以注明所用定义
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct QiZhengOptions {
+    /// 四余的取法
+    pub remainders: RemainderOptions,
+    /// 入宿所用的宿度表
+    pub mansions: MansionOptions,
+    /// 命宫的定法
+    pub ming: MingMethod,
+    /// 十二次的取法
+    pub stations: CiOptions,
+    /// 顺逆留伏迟疾的判断范围
+    pub motion: MotionOptions,
+}
+
+impl QiZhengOptions {
+    /// 以给定的紫气起例建立排盘选项，其余各项取默认
+    pub fn new(zi_qi: ZiQiModel) -> Self {
+        QiZhengOptions {
+            remainders: RemainderOptions::new(zi_qi),
+            mansions: MansionOptions::default(),
+            ming: MingMethod::default(),
+            stations: CiOptions::default(),
+            motion: MotionOptions::default(),
+        }
+    }
+}
+
+/// 一曜所入之宿与所在之宫
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BodyPlacement {
+    /// 七政或四余
+    pub body: Body,
+    /// 黄经（度）
+    pub longitude: f64,
+    /// 所在之宿
+    pub mansion: XiuMansion,
+    /// 入宿度
+    pub degree: f64,
+    /// 所在之次
+    pub station: CiStation,
+    /// 所在的地支宫
+    pub branch: EarthlyBranch,
+    /// 所在的十二宫
+    pub palace: Palace,
+    /// 距日角距（度），太阳为0
+    pub elongation: f64,
+    /// 顺逆留伏迟疾
+    pub motion: MotionState,
+}
+
+/// 十二宫之一及其中的星曜
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PalaceCell {
+    /// 宫名
+    pub palace: Palace,
+    /// 所在的地支宫
+    pub branch: EarthlyBranch,
+    /// 宫中的七政四余，按日、月、水、金、火、木、土、罗、计、孛、气排列
+    pub bodies: Vec<Body>,
+}
 
 #[derive(Serialize)]
 pub struct QiZhengPan {
-    // Placeholder fields for QiZhengPan
-    // In a full implementation, this would contain the positions of the seven luminaries
-    // and four remaining bodies (七政四余)
-    pub sun: String, // Placeholder
-    pub moon: String, // Placeholder
-    pub mars: String, // Placeholder
-    pub mercury: String, // Placeholder
-    pub jupiter: String, // Placeholder
-    pub venus: String, // Placeholder
-    pub saturn: String, // Placeholder
-    pub star1: String, // Placeholder for one of the "remaining" stars
-    pub star2: String, // Placeholder for one of the "remaining" stars
-    pub star3: String, // Placeholder for one of the "remaining" stars
-    pub star4: String, // Placeholder for one of the "remaining" stars
+    /// 世界时儒略日
+    pub julian_day: f64,
+    pub sun: Position,
+    pub moon: Position,
+    pub mars: Position,
+    pub mercury: Position,
+    pub jupiter: Position,
+    pub venus: Position,
+    pub saturn: Position,
+    pub luo_hou: Position,
+    pub ji_du: Position,
+    pub yue_bei: Position,
+    pub zi_qi: Position,
+    /// 出生地
+    pub location: Location,
+    /// 上升点黄经（度）
+    pub ascendant: f64,
+    /// 命度：命宫所起的黄经（度）
+    pub ming_degree: f64,
+    /// 命宫所在的地支宫
+    pub ming_branch: EarthlyBranch,
+    /// 命度所入之宿
+    pub ming_mansion: MansionPlacement,
+    /// 十二宫，自命宫起
+    pub palaces: Vec<PalaceCell>,
+    /// 黄道宿度表
+    pub mansion_table: MansionTable,
+    /// 七政四余各曜的入宿度，按日、月、水、金、火、木、土、罗、计、孛、气排列
+    pub placements: Vec<BodyPlacement>,
+    /// 所用的排盘选项
+    pub options: QiZhengOptions,
+    /// 各曜的化曜，按日、月、水、金、火、木、土、罗、计、孛、气排列；经 annotate_hua_yao 标注前为空
+    pub hua_yao: Vec<BodyHuaYao>,
 }
 
 impl QiZhengPan {
-    pub fn from_julian_day(julian_day: f64) -> Self {
-        // This would normally use the Swiss Ephemeris to calculate positions
-        // of the seven luminaries (Sun, Moon, Mars, Mercury, Jupiter, Venus, Saturn)
-        // and four additional stars/celestial bodies
-        QiZhengPan {
-            sun: "未实现".to_string(),
-            moon: "未实现".to_string(),
-            mars: "未实现".to_string(),
-            mercury: "未实现".to_string(),
-            jupiter: "未实现".to_string(),
-            venus: "未实现".to_string(),
-            saturn: "未实现".to_string(),
-            star1: "未实现".to_string(),
-            star2: "未实现".to_string(),
-            star3: "未实现".to_string(),
-            star4: "未实现".to_string(),
-        }
-    }
-}
\ No newline at end of file
+    /// 由世界时儒略日与出生地排盘，紫气按给定起例，其余使用默认选项
+    pub fn from_julian_day(julian_day: f64, location: Location, zi_qi: ZiQiModel) -> Result<Self, EphemerisError> {
+        Self::from_julian_day_with(julian_day, location, &QiZhengOptions::new(zi_qi))
+    }
+
+    /// 由世界时儒略日与出生地按给定选项排盘
+    pub fn from_julian_day_with(julian_day: f64, location: Location, options: &QiZhengOptions) -> Result<Self, EphemerisError> {
+        // 整张盘共用一个星历句柄
+        let eph = ephemeris::open()?;
+        let position = |luminary: Luminary| Position::of_body(&eph, luminary.body(), julian_day);
+        let sun = position(Luminary::Sun)?;
+        let [luo_hou, ji_du, yue_bei, zi_qi] = options.remainders.positions_in(&eph, julian_day)?;
+        let epoch = options.mansions.epoch.unwrap_or(julian_day);
+        let mansion_table = mansion_table_in(&eph, MansionFrame::Ecliptic, options.mansions.boundary, epoch)?;
+        let (ascendant, _) = ephemeris::ascendant(&eph, julian_day, location.longitude, location.latitude)?;
+        let ming_degree = match options.ming {
+            MingMethod::Ascendant => ascendant,
+            MingMethod::Sunrise => {
+                // 卯为第四支，每迟一时辰命度顺黄道进30°
+                let steps = hour_branch(julian_day, location.longitude).index() as f64 - 4.0;
+                (sun.longitude + steps * 30.0).rem_euclid(360.0)
+            }
+        };
+        let ming_branch = branch_of(ming_degree);
+        let ci_offset = options.stations.offset_in(&eph, julian_day)?;
+        let calc = options.motion.calculate();
+        let mut pan = QiZhengPan {
+            julian_day,
+            sun,
+            moon: position(Luminary::Moon)?,
+            mars: position(Luminary::Mars)?,
+            mercury: position(Luminary::Mercury)?,
+            jupiter: position(Luminary::Jupiter)?,
+            venus: position(Luminary::Venus)?,
+            saturn: position(Luminary::Saturn)?,
+            luo_hou,
+            ji_du,
+            yue_bei,
+            zi_qi,
+            location,
+            ascendant,
+            ming_degree,
+            ming_branch,
+            ming_mansion: mansion_table.locate(ming_degree),
+            palaces: Vec::new(),
+            mansion_table,
+            placements: Vec::new(),
+            options: *options,
+            hua_yao: Vec::new(),
+        };
+        pan.placements = pan
+            .bodies()
+            .iter()
+            .map(|(body, position)| {
+                let MansionPlacement { mansion, degree } = pan.mansion_table.locate(position.longitude);
+                let branch = branch_of(position.longitude);
+                // 七政由 swe_pheno 求距日角距；四余为虚点，取黄经之差，且只分顺逆
+                let (se_body, elongation) = match body {
+                    Body::Luminary(luminary) => (luminary.body(), ephemeris::elongation(&eph, julian_day, luminary.body())?),
+                    Body::Remainder(_) => (-1, Calculate::get_degree_gap(position.longitude, sun.longitude)),
+                };
+                let state = calc.get_speed_state(se_body, position.speed, elongation);
+                Ok(BodyPlacement {
+                    body: *body,
+                    longitude: position.longitude,
+                    mansion,
+                    degree,
+                    station: CiStation::from_longitude(position.longitude - ci_offset, options.stations.boundary).station,
+                    branch,
+                    palace: Palace::at(ming_branch, branch),
+                    elongation,
+                    motion: MotionState::from_speed_state(&calc, state),
+                })
+            })
+            .collect::<Result<_, EphemerisError>>()?;
+        pan.palaces = Palace::ALL
+            .iter()
+            .map(|palace| PalaceCell {
+                palace: *palace,
+                branch: palace.branch(ming_branch),
+                bodies: pan.placements.iter().filter(|p| p.palace == *palace).map(|p| p.body).collect(),
+            })
+            .collect();
+        Ok(pan)
+    }
+
+    /// 七政及其位置，按日、月、水、金、火、木、土排列
+    pub fn luminaries(&self) -> [(Luminary, &Position); 7] {
+        [
+            (Luminary::Sun, &self.sun),
+            (Luminary::Moon, &self.moon),
+            (Luminary::Mercury, &self.mercury),
+            (Luminary::Venus, &self.venus),
+            (Luminary::Mars, &self.mars),
+            (Luminary::Jupiter, &self.jupiter),
+            (Luminary::Saturn, &self.saturn),
+        ]
+    }
+
+    /// 四余及其位置，按罗、计、孛、气排列
+    pub fn remainders(&self) -> [(Remainder, &Position); 4] {
+        [
+            (Remainder::LuoHou, &self.luo_hou),
+            (Remainder::JiDu, &self.ji_du),
+            (Remainder::YueBei, &self.yue_bei),
+            (Remainder::ZiQi, &self.zi_qi),
+        ]
+    }
+
+    /// 七政四余十一曜及其位置，七政在前、四余在后
+    pub fn bodies(&self) -> [(Body, &Position); 11] {
+        let [sun, moon, mercury, venus, mars, jupiter, saturn] =
+            self.luminaries().map(|(luminary, position)| (Body::Luminary(luminary), position));
+        let [luo_hou, ji_du, yue_bei, zi_qi] =
+            self.remainders().map(|(remainder, position)| (Body::Remainder(remainder), position));
+        [sun, moon, mercury, venus, mars, jupiter, saturn, luo_hou, ji_du, yue_bei, zi_qi]
+    }
+
+    /// 某一曜所入之宿与所在之宫
+    pub fn placement(&self, body: Body) -> Option<&BodyPlacement> {
+        self.placements.iter().find(|placement| placement.body == body)
+    }
+
+    /// 某一曜的顺逆留伏迟疾
+    pub fn motion(&self, body: Body) -> Option<MotionState> {
+        self.placement(body).map(|placement| placement.motion)
+    }
+
+    /// 十一曜用于相位计算的黄经与速度
+    pub fn aspect_points(&self) -> Vec<AspectPoint<Body>> {
+        self.bodies()
+            .iter()
+            .map(|(body, position)| AspectPoint { body: *body, longitude: position.longitude, speed: position.speed })
+            .collect()
+    }
+
+    /// 盘中各曜两两之间的相位；给出 search 时并求排盘时刻前后最近的精确成相之时
+    pub fn aspects(&self, set: &AspectSet, search: Option<&SearchOptions>) -> Result<Vec<Aspect<Body>>, EphemerisError> {
+        let mut aspects = aspect::aspects_within(&self.aspect_points(), set);
+        if let Some(options) = search {
+            let eph = ephemeris::open()?;
+            let remainders = &self.options.remainders;
+            for found in aspects.iter_mut() {
+                let difference = |t: f64| {
+                    let (first, second) = (found.first.position_in(&eph, t, remainders)?, found.second.position_in(&eph, t, remainders)?);
+                    Ok((first.longitude - second.longitude, first.speed - second.speed))
+                };
+                found.exact = aspect::exact_time(difference, found, self.julian_day, options)?;
+            }
+        }
+        Ok(aspects)
+    }
+
+    /// 本盘（本命）各曜与 transit（流年、流月等）各曜之间的相位，first 为本命、second 为流盘
+    /// 给出 search 时并求流盘时刻前后最近的精确成相之时，本命各曜视为不动
+    pub fn aspects_to(&self, transit: &QiZhengPan, set: &AspectSet, search: Option<&SearchOptions>) -> Result<Vec<Aspect<Body>>, EphemerisError> {
+        // 本命各曜定于出生之时，入相出相只看流盘各曜的行度
+        let natal: Vec<_> = self.aspect_points().into_iter().map(|point| AspectPoint { speed: 0.0, ..point }).collect();
+        let mut aspects = aspect::aspects_between(&natal, &transit.aspect_points(), set);
+        if let Some(options) = search {
+            let eph = ephemeris::open()?;
+            let remainders = &transit.options.remainders;
+            for found in aspects.iter_mut() {
+                let natal = self.bodies().iter().find(|(body, _)| *body == found.first).map(|(_, p)| p.longitude).unwrap_or_default();
+                let difference = |t: f64| {
+                    let moving = found.second.position_in(&eph, t, remainders)?;
+                    Ok((natal - moving.longitude, -moving.speed))
+                };
+                found.exact = aspect::exact_time(difference, found, transit.julian_day, options)?;
+            }
+        }
+        Ok(aspects)
+    }
+
+    /// 按化曜表标注各曜在本命年干与流年年干下的化曜
+    /// 本命年干由排盘时刻按立春交节（太阳黄经315°）定年，立春前出生者仍用上一年的年干
+    pub fn annotate_hua_yao(&mut self, table: &HuaYaoTable, flow: Option<FlowYear>) -> Result<(), EphemerisError> {
+        let natal = *jie_qi::year_gan_zhi(self.julian_day)?.stem();
+        let flow = flow.map(|year| year.stem()).transpose()?;
+        self.hua_yao = Body::ALL
+            .into_iter()
+            .map(|body| BodyHuaYao {
+                body,
+                natal: table.roles(natal, body),
+                flow: flow.map(|stem| table.roles(stem, body)).unwrap_or_default(),
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// 某一曜的化曜，未标注时为 None
+    pub fn hua_yao_of(&self, body: Body) -> Option<&BodyHuaYao> {
+        self.hua_yao.iter().find(|annotation| annotation.body == body)
+    }
+
+    /// 某一宫及其中的星曜
+    pub fn palace(&self, palace: Palace) -> &PalaceCell {
+        &self.palaces[palace as usize]
+    }
+}
+
+/// 世界时儒略日在某经度的地方平太阳时所当时辰，子时为23至1时
+pub fn hour_branch(julian_day: f64, longitude: f64) -> EarthlyBranch {
+    let hours = ((julian_day + 0.5).rem_euclid(1.0) * 24.0 + longitude / 15.0).rem_euclid(24.0);
+    EarthlyBranch::from_index(((hours + 1.0) / 2.0) as usize % 12 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::swisseph::SE_SIDM_LAHIRI;
+    use crate::concepts::traits::Iter;
+
+    const BEIJING: Location = Location { longitude: 116.4, latitude: 39.9 };
+    // 测试用的紫气起例：J2000.0 时在黄经0°
+    const ZI_QI: ZiQiModel = ZiQiModel { epoch: 2451545.0, longitude: 0.0, period_years: ZiQiModel::PERIOD_YEARS };
+
+    #[test]
+    fn test_from_julian_day() {
+        // 2000年1月1日12时（世界时）：太阳约280.37°，月亮约223.32°，土星处于逆行段
+        let pan = QiZhengPan::from_julian_day(2451545.0, BEIJING, ZI_QI).unwrap();
+        assert!((pan.sun.longitude - 280.37).abs() < 0.01);
+        assert!((pan.moon.longitude - 223.32).abs() < 0.01);
+        assert!(pan.moon.speed > 11.0);
+        assert!(pan.saturn.retrograde);
+        for (luminary, position) in pan.luminaries() {
+            assert!((0.0..360.0).contains(&position.longitude));
+            assert_eq!(position.retrograde, luminary == Luminary::Saturn);
+        }
+        // 2000年3月1日水星逆行
+        let pan = QiZhengPan::from_julian_day(2451604.5, BEIJING, ZI_QI).unwrap();
+        assert!(pan.mercury.retrograde);
+    }
+
+    #[test]
+    fn test_remainders() {
+        // J2000.0 平升交点约125.04°，平远地点约263.46°
+        let pan = QiZhengPan::from_julian_day(2451545.0, BEIJING, ZI_QI).unwrap();
+        assert!((pan.luo_hou.longitude - 125.04).abs() < 0.01);
+        assert!((pan.ji_du.longitude - 305.04).abs() < 0.01);
+        assert!(pan.luo_hou.retrograde && pan.ji_du.retrograde);
+        assert!((pan.yue_bei.longitude - 263.46).abs() < 0.01);
+        // 紫气按排盘时刻推算，而非照抄历元黄经：历元后2000日行约70.40°
+        let later = QiZhengPan::from_julian_day(2451545.0 + 2000.0, BEIJING, ZI_QI).unwrap();
+        assert!((later.zi_qi.longitude - 70.40).abs() < 0.01);
+        assert!((later.zi_qi.speed - pan.zi_qi.speed).abs() < 1e-12 && !later.zi_qi.retrograde);
+
+        let options = RemainderOptions {
+            node: NodeModel::True,
+            luo_hou: LuoHouNode::Descending,
+            apogee: ApogeeModel::Osculating,
+            zi_qi: ZiQiModel::new(2451545.0, 90.0),
+        };
+        let options_with = QiZhengOptions { remainders: options, ..QiZhengOptions::new(ZI_QI) };
+        let swapped = QiZhengPan::from_julian_day_with(2451545.0, BEIJING, &options_with).unwrap();
+        assert!((swapped.ji_du.longitude - pan.luo_hou.longitude).abs() < 2.0);
+        assert!((swapped.luo_hou.longitude - swapped.ji_du.longitude - 180.0).abs() < 1e-9);
+        assert_eq!(swapped.options.remainders, options);
+
+        // 紫气二十八年一周天，七年行90°，即三宫（每宫30°）
+        let later = options.zi_qi.position(2451545.0 + 7.0 * ZiQiModel::TROPICAL_YEAR);
+        assert!((later.longitude - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mansions() {
+        // J2000.0 黄道上井宿约30.4°、觜宿不足1°；黄道、赤道距星均按宿序排列
+        let table = mansion_table(MansionFrame::Ecliptic, MansionBoundary::Modern, 2451545.0).unwrap();
+        assert!((table.start(XiuMansion::JiaoMuJiao) - 203.84).abs() < 0.02);
+        assert!((table.width(XiuMansion::JingMuAn) - 30.43).abs() < 0.02);
+        assert!(table.width(XiuMansion::ZiHuoHou) < 1.0);
+        for frame in [MansionFrame::Ecliptic, MansionFrame::Equatorial] {
+            let table = mansion_table(frame, MansionBoundary::Modern, 2451545.0).unwrap();
+            for mansion in XiuMansion::ALL {
+                let gap = (table.start(mansion.next()) - table.start(mansion) + 360.0) % 360.0;
+                assert!((gap - table.width(mansion)).abs() < 1e-9);
+            }
+        }
+
+        // 2000年1月1日太阳黄经约280.37°，在斗宿（φ Sgr 黄经约279.3°）
+        let pan = QiZhengPan::from_julian_day(2451545.0, BEIJING, ZI_QI).unwrap();
+        assert_eq!(pan.placements.len(), 11);
+        let sun = pan.placement(Body::Luminary(Luminary::Sun)).unwrap();
+        assert_eq!(sun.mansion, XiuMansion::DouMuXie);
+        assert!(sun.degree > 0.0 && sun.degree < pan.mansion_table.width(XiuMansion::DouMuXie));
+        let zi_qi = pan.placement(Body::Remainder(Remainder::ZiQi)).unwrap();
+        assert_eq!(zi_qi.longitude, pan.zi_qi.longitude);
+
+        let options = QiZhengOptions {
+            mansions: MansionOptions { boundary: MansionBoundary::Ancient, epoch: Some(2451545.0) },
+            ..QiZhengOptions::new(ZI_QI)
+        };
+        let ancient = QiZhengPan::from_julian_day_with(2451545.0, BEIJING, &options).unwrap();
+        assert_eq!(ancient.mansion_table.start(XiuMansion::JiaoMuJiao), pan.mansion_table.start(XiuMansion::JiaoMuJiao));
+    }
+
+    #[test]
+    fn test_palaces() {
+        assert_eq!(branch_of(0.0), EarthlyBranch::Xu);
+        assert_eq!(branch_of(315.0), EarthlyBranch::Zi);
+        assert_eq!(branch_of(359.9), EarthlyBranch::Hai);
+        assert_eq!(Palace::at(EarthlyBranch::Zi, EarthlyBranch::Hai), Palace::CaiBo);
+        assert_eq!(Palace::QiQie.branch(EarthlyBranch::Zi), EarthlyBranch::Wu);
+        // 世界时0时为北京地方平太阳时约7时46分，辰时
+        assert_eq!(hour_branch(2451544.5, BEIJING.longitude), EarthlyBranch::Chen);
+        assert_eq!(hour_branch(2451544.5 - 8.0 / 24.0, BEIJING.longitude), EarthlyBranch::Zi);
+
+        // 北京2000年1月1日日出（世界时约前一日23时36分）上升点与太阳黄经相近
+        let pan = QiZhengPan::from_julian_day(2451544.4833, BEIJING, ZI_QI).unwrap();
+        assert!((pan.ascendant - pan.sun.longitude).abs() < 1.5);
+        assert_eq!(pan.ming_branch, EarthlyBranch::Chou);
+        assert_eq!(pan.ming_degree, pan.ascendant);
+        assert_eq!(pan.ming_mansion, pan.mansion_table.locate(pan.ascendant));
+        let sun = pan.placement(Body::Luminary(Luminary::Sun)).unwrap();
+        assert_eq!((sun.branch, sun.palace), (EarthlyBranch::Chou, Palace::Ming));
+        assert_eq!(pan.palaces.len(), 12);
+        assert_eq!(pan.palace(Palace::Ming).branch, EarthlyBranch::Chou);
+        assert!(pan.palace(Palace::Ming).bodies.contains(&Body::Luminary(Luminary::Sun)));
+        assert_eq!(pan.palaces.iter().map(|cell| cell.bodies.len()).sum::<usize>(), 11);
+
+        // 太阳加时：卯时命宫即太阳所在之宫，辰时移至下一宫
+        let options = QiZhengOptions { ming: MingMethod::Sunrise, ..QiZhengOptions::new(ZI_QI) };
+        // 世界时22时为北京地方平太阳时约5时46分，卯时
+        let pan = QiZhengPan::from_julian_day_with(2451544.5 - 2.0 / 24.0, BEIJING, &options).unwrap();
+        assert_eq!(pan.ming_branch, EarthlyBranch::Chou);
+        let pan = QiZhengPan::from_julian_day_with(2451544.5, BEIJING, &options).unwrap();
+        assert_eq!(pan.ming_branch, EarthlyBranch::Zi);
+    }
+
+    #[test]
+    fn test_stations() {
+        // 回归黄道、中气起次时，十二次与地支宫一致
+        for longitude in [0.0, 95.0, 280.37, 359.9] {
+            let placement = ci_station(longitude, 2451545.0, &CiOptions::default()).unwrap();
+            assert_eq!(placement.station.branch(), branch_of(longitude));
+        }
+        // Lahiri 恒星黄道下太阳（回归黄经280.37°）约在256.5°，入析木
+        let options = CiOptions { zodiac: Zodiac::Sidereal(SE_SIDM_LAHIRI), boundary: CiBoundary::ZhongQi };
+        let placement = ci_station(280.37, 2451545.0, &options).unwrap();
+        assert_eq!(placement.station, CiStation::XiMu);
+        assert!((placement.degree - 16.5).abs() < 0.1);
+
+        let pan = QiZhengPan::from_julian_day(2451545.0, BEIJING, ZI_QI).unwrap();
+        let sun = pan.placement(Body::Luminary(Luminary::Sun)).unwrap();
+        assert_eq!(sun.station, CiStation::XingJi);
+        let options = QiZhengOptions { stations: options, ..QiZhengOptions::new(ZI_QI) };
+        let pan = QiZhengPan::from_julian_day_with(2451545.0, BEIJING, &options).unwrap();
+        let sun = pan.placement(Body::Luminary(Luminary::Sun)).unwrap();
+        assert_eq!((sun.station, sun.branch), (CiStation::XiMu, EarthlyBranch::Chou));
+    }
+
+    #[test]
+    fn test_motion() {
+        // 2000年1月1日：太阳近近日点而疾，水星距日约8.5°而伏，土星逆行，木星迟，罗计逆行
+        let pan = QiZhengPan::from_julian_day(2451545.0, BEIJING, ZI_QI).unwrap();
+        assert_eq!(pan.motion(Body::Luminary(Luminary::Sun)), Some(MotionState::Fast));
+        assert_eq!(pan.motion(Body::Luminary(Luminary::Mercury)), Some(MotionState::Invisible));
+        assert_eq!(pan.motion(Body::Luminary(Luminary::Saturn)), Some(MotionState::Retrograde));
+        assert_eq!(pan.motion(Body::Luminary(Luminary::Jupiter)), Some(MotionState::Slow));
+        assert_eq!(pan.motion(Body::Remainder(Remainder::LuoHou)), Some(MotionState::Retrograde));
+        assert_eq!(pan.motion(Body::Remainder(Remainder::ZiQi)), Some(MotionState::Normal));
+        let mercury = pan.placement(Body::Luminary(Luminary::Mercury)).unwrap();
+        assert!((mercury.elongation - 8.54).abs() < 0.05);
+
+        // 土星日行约-0.02°，放宽留的范围后为留；不判断伏时水星为疾；不判断迟时木星为顺
+        let mut motion = MotionOptions::default();
+        motion.stationary_gap[Luminary::Saturn as usize] = 0.03;
+        motion.invisible_gap[Luminary::Mercury as usize] = 0.0;
+        motion.slow_speed[Luminary::Jupiter as usize] = 0.0;
+        let options = QiZhengOptions { motion, ..QiZhengOptions::new(ZI_QI) };
+        let pan = QiZhengPan::from_julian_day_with(2451545.0, BEIJING, &options).unwrap();
+        assert_eq!(pan.motion(Body::Luminary(Luminary::Saturn)), Some(MotionState::Stationary));
+        assert_eq!(pan.motion(Body::Luminary(Luminary::Mercury)), Some(MotionState::Fast));
+        assert_eq!(pan.motion(Body::Luminary(Luminary::Jupiter)), Some(MotionState::Normal));
+        assert_eq!(pan.options.motion, motion);
+    }
+
+    #[test]
+    fn test_aspects() {
+        use crate::calendar::aspect::{AspectKind, AspectPhase};
+        let options = SearchOptions::default();
+        let sun = Body::Luminary(Luminary::Sun);
+        let saturn = Body::Luminary(Luminary::Saturn);
+
+        // 2000年1月1日日土相距约120.03°，入相三合，约37分钟后正相
+        let pan = QiZhengPan::from_julian_day(2451545.0, BEIJING, ZI_QI).unwrap();
+        let aspects = pan.aspects(&AspectSet::western(), Some(&options)).unwrap();
+        assert!(aspects.iter().all(|aspect| aspect.first != aspect.second));
+        let trine = aspects.iter().find(|aspect| (aspect.first, aspect.second) == (sun, saturn)).unwrap();
+        assert_eq!((trine.kind, trine.phase), (AspectKind::Trine, AspectPhase::Applying));
+        let exact = trine.exact.unwrap();
+        assert!((exact - 2451545.026).abs() < 0.01);
+        let remainders = RemainderOptions::new(ZI_QI);
+        let gap = Calculate::get_degree_gap(
+            sun.position(exact, &remainders).unwrap().longitude,
+            saturn.position(exact, &remainders).unwrap().longitude,
+        );
+        assert!((gap - 120.0).abs() < 1e-3);
+        // 罗计恒相对照
+        let nodes = aspects.iter().find(|aspect| aspect.first == Body::Remainder(Remainder::LuoHou)
+            && aspect.second == Body::Remainder(Remainder::JiDu)).unwrap();
+        assert_eq!((nodes.kind, nodes.phase), (AspectKind::Opposition, AspectPhase::Exact));
+
+        // 本命与一年后的流盘：流日回到本命日度即太阳回归
+        let transit = QiZhengPan::from_julian_day(2451545.0 + 365.25, BEIJING, ZI_QI).unwrap();
+        let aspects = pan.aspects_to(&transit, &AspectSet::western(), Some(&options)).unwrap();
+        let solar_return = aspects.iter().find(|aspect| (aspect.first, aspect.second) == (sun, sun)).unwrap();
+        assert_eq!(solar_return.kind, AspectKind::Conjunction);
+        assert!((solar_return.exact.unwrap() - 2451910.24).abs() < 0.01);
+        // 按宫论不问度数：本命水星在丑宫、流年土星在酉宫，相隔四宫为三合
+        let aspects = pan.aspects_to(&transit, &AspectSet::qi_zheng(), None).unwrap();
+        let mercury_saturn = aspects.iter().find(|aspect| (aspect.first, aspect.second) == (Body::Luminary(Luminary::Mercury), saturn)).unwrap();
+        assert_eq!(mercury_saturn.kind, AspectKind::Trine);
+        assert!(mercury_saturn.orb > 20.0 && mercury_saturn.exact.is_none());
+
+        // 本命月亮日行约13°，但本命不动：流日在本命月亮之后约57°，日行向六合（60°）靠近，为入相
+        let moon = Body::Luminary(Luminary::Moon);
+        let aspects = pan.aspects_to(&pan, &AspectSet::western(), None).unwrap();
+        let moon_sun = aspects.iter().find(|aspect| (aspect.first, aspect.second) == (moon, sun)).unwrap();
+        assert_eq!((moon_sun.kind, moon_sun.phase), (AspectKind::Sextile, AspectPhase::Applying));
+        assert!((moon_sun.orb + 2.95).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_hua_yao() {
+        // 《张果星宗》甲干：禄火、暗孛、福木、耗金、荫土、贵月、刑水、印炁、囚计、权罗
+        let table = HuaYaoTable::guo_lao();
+        let mars = Body::Luminary(Luminary::Mars);
+        let zi_qi = Body::Remainder(Remainder::ZiQi);
+        let published: Vec<Body> = "火孛木金土月水炁计罗".chars().map(|name| Body::from_short_name(name).unwrap()).collect();
+        assert_eq!(table.rows[0].to_vec(), published);
+        assert_eq!(table.body(HeavenlyStem::Jia, HuaYao::Lu), mars);
+        assert_eq!(table.body(HeavenlyStem::Gui, HuaYao::Lu), Body::Remainder(Remainder::LuoHou));
+        assert_eq!(table.roles(HeavenlyStem::Yi, zi_qi), [HuaYao::Xing]);
+        // 太阳不化
+        for stem in HeavenlyStem::ALL {
+            assert!(table.roles(stem, Body::Luminary(Luminary::Sun)).is_empty());
+        }
+
+        // 简称可用“气”，可夹顿号；字数不足或有不识之字时报错
+        let mut rows = [
+            "火孛木金土月水炁计罗", "孛木金土月水炁计罗火", "木金土月水炁计罗火孛", "金土月水炁计罗火孛木", "土月水炁计罗火孛木金",
+            "月水炁计罗火孛木金土", "水炁计罗火孛木金土月", "炁计罗火孛木金土月水", "计罗火孛木金土月水炁", "罗火孛木金土月水炁计",
+        ];
+        rows[0] = "火、孛、木、金、土、月、水、气、计、罗";
+        assert_eq!(HuaYaoTable::from_rows(rows).unwrap(), table);
+        rows[0] = "火孛木金土月水气计";
+        assert!(HuaYaoTable::from_rows(rows).is_err());
+        rows[0] = "火孛木金土月水气计星";
+        assert!(HuaYaoTable::from_rows(rows).unwrap_err().message.contains('星'));
+
+        // 2000年1月1日在2000年立春之前，本命仍为己卯年：己干化福者为紫气
+        let mut pan = QiZhengPan::from_julian_day(2451545.0, BEIJING, ZI_QI).unwrap();
+        assert!(pan.hua_yao_of(mars).is_none());
+        pan.annotate_hua_yao(&table, Some(FlowYear::Stem(HeavenlyStem::Jia))).unwrap();
+        assert_eq!(pan.hua_yao.len(), 11);
+        let annotation = pan.hua_yao_of(zi_qi).unwrap();
+        assert_eq!(annotation.natal, [HuaYao::Fu]);
+        assert_eq!(annotation.flow, [HuaYao::Yin]);
+        // 流年2001年1月1日亦在立春之前，为庚辰年：庚干化暗者为紫气
+        pan.annotate_hua_yao(&table, Some(FlowYear::JulianDay(2451545.0 + 366.0))).unwrap();
+        assert_eq!(pan.hua_yao_of(zi_qi).unwrap().flow, [HuaYao::An]);
+        pan.annotate_hua_yao(&table, None).unwrap();
+        assert!(pan.hua_yao_of(mars).unwrap().flow.is_empty());
+    }
+}
@@ -0,0 +1,214 @@
+// src/metaphysics/xuan_kong.rs - 玄空飞星
+// 由元运与立向度数排出运盘、山盘、向盘，兼向过多时用替卦，并判断旺山旺向等格局
+use alloc::format;
+use alloc::vec::Vec;
+use serde::Serialize;
+use crate::calendar::calculate::Calculate;
+use crate::concepts::mountain::Mountain;
+use crate::concepts::nine_star::{FlyingStars, NinePalace, NineStar};
+use crate::concepts::traits::{ChineseName, YinYangTrait};
+use crate::concepts::yinyang::YinYang;
+use crate::json::MingbuError;
+
+// 替卦口诀：子癸并甲申，贪狼一路行；坤壬乙卯未，五位为巨门；
+// 乾亥辰巽巳，连戌武曲名；酉辛丑艮丙，星星是右弼；寅午庚丁上，破军七星尊
+fn replacement_star(mountain: Mountain) -> NineStar {
+    match mountain {
+        Mountain::Zi | Mountain::Gui | Mountain::Jia | Mountain::Shen => NineStar::YiBai,
+        Mountain::Kun | Mountain::Ren | Mountain::Yi | Mountain::Mao | Mountain::Wei => NineStar::ErHei,
+        Mountain::Qian | Mountain::Hai | Mountain::Chen | Mountain::Xun | Mountain::Si | Mountain::Xu => NineStar::LiuBai,
+        Mountain::You | Mountain::Xin | Mountain::Chou | Mountain::Gen | Mountain::Bing => NineStar::JiuZi,
+        Mountain::Yin | Mountain::Wu | Mountain::Geng | Mountain::Ding => NineStar::QiChi,
+    }
+}
+
+/// 立向距山界不足此度数时为兼向，用替卦起星
+pub const REPLACEMENT_MARGIN: f64 = 3.0;
+
+fn palace_of(mountain: Mountain) -> NinePalace {
+    NinePalace::from_direction(mountain.direction())
+}
+
+// 同元龙的山：星数本宫中与 mountain 元龙相同的一山
+fn counterpart(star: NineStar, mountain: Mountain) -> Mountain {
+    Mountain::from_trigram(star.home_palace().trigram().unwrap(), mountain.yuan_long())
+}
+
+/// 玄空格局
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Formation {
+    /// 旺山旺向：当运山星到坐、向星到向
+    WangShanWangXiang,
+    /// 上山下水：当运山星到向、向星到坐
+    ShangShanXiaShui,
+    /// 双星会向：当运山星、向星同到向首
+    ShuangXingHuiXiang,
+    /// 双星会坐：当运山星、向星同到坐山
+    ShuangXingHuiZuo,
+    /// 伏吟：五黄入中顺飞，各宫星与本宫同数
+    FuYin,
+    /// 反吟：五黄入中逆飞，各宫星与对宫同数
+    FanYin,
+}
+
+impl ChineseName for Formation {
+    fn chinese_name(&self) -> &'static str {
+        match self {
+            Formation::WangShanWangXiang => "旺山旺向",
+            Formation::ShangShanXiaShui => "上山下水",
+            Formation::ShuangXingHuiXiang => "双星会向",
+            Formation::ShuangXingHuiZuo => "双星会坐",
+            Formation::FuYin => "伏吟",
+            Formation::FanYin => "反吟",
+        }
+    }
+}
+
+/// 玄空飞星盘
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct XuanKong {
+    /// 元运，即运星
+    pub period: NineStar,
+    /// 立向度数（已归一到 0..360）
+    pub facing: f64,
+    /// 向首所在的山
+    pub facing_mountain: Mountain,
+    /// 坐山
+    pub sitting_mountain: Mountain,
+    /// 向首在所在山内的度数偏移（0..15）
+    pub facing_offset: f64,
+    /// 向首所在的宫
+    pub facing_palace: NinePalace,
+    /// 坐山所在的宫
+    pub sitting_palace: NinePalace,
+    /// 是否兼向用替卦
+    pub replacement: bool,
+    /// 运盘
+    pub period_chart: FlyingStars,
+    /// 山盘（坐星）
+    pub mountain_chart: FlyingStars,
+    /// 向盘（向星）
+    pub water_chart: FlyingStars,
+    /// 格局
+    pub formations: Vec<Formation>,
+}
+
+impl XuanKong {
+    /// 由元运与立向度数（正北为0，顺时针）排盘，不作磁偏修正
+    pub fn new(period: NineStar, facing: f64) -> Result<Self, MingbuError> {
+        Self::from_calculate(&Calculate::new(), period, facing)
+    }
+
+    /// 使用 Calculate 的二十四山度数划分（含 set_mountain_offset 设置的偏移）排盘
+    pub fn from_calculate(calc: &Calculate, period: NineStar, facing: f64) -> Result<Self, MingbuError> {
+        if !facing.is_finite() {
+            return Err(MingbuError {
+                code: "XUAN_KONG_ERROR",
+                message: format!("立向度数无效: {}", facing),
+            });
+        }
+        let facing = facing.rem_euclid(360.0);
+        let (facing_mountain, facing_offset) = calc.get_mountain(facing);
+        let sitting_mountain = facing_mountain.opposite();
+        let replacement = !(REPLACEMENT_MARGIN..=15.0 - REPLACEMENT_MARGIN).contains(&facing_offset);
+
+        let period_chart = period.fly(true);
+        let facing_palace = palace_of(facing_mountain);
+        let sitting_palace = palace_of(sitting_mountain);
+        let fly = |star: NineStar, mountain: Mountain| {
+            // 五黄无本宫，以坐山或向首本身定阴阳与替星
+            let origin = if star == NineStar::WuHuang { mountain } else { counterpart(star, mountain) };
+            let center = if replacement { replacement_star(origin) } else { star };
+            center.fly(origin.yinyang() == YinYang::Yang)
+        };
+        let mountain_chart = fly(period_chart.star_at(sitting_palace), sitting_mountain);
+        let water_chart = fly(period_chart.star_at(facing_palace), facing_mountain);
+
+        let mut formations = Vec::new();
+        let mountain_at = mountain_chart.palace_of(period);
+        let water_at = water_chart.palace_of(period);
+        if mountain_at == sitting_palace && water_at == facing_palace {
+            formations.push(Formation::WangShanWangXiang);
+        } else if mountain_at == facing_palace && water_at == sitting_palace {
+            formations.push(Formation::ShangShanXiaShui);
+        } else if mountain_at == facing_palace && water_at == facing_palace {
+            formations.push(Formation::ShuangXingHuiXiang);
+        } else if mountain_at == sitting_palace && water_at == sitting_palace {
+            formations.push(Formation::ShuangXingHuiZuo);
+        }
+        for chart in [&mountain_chart, &water_chart] {
+            if chart.center == NineStar::WuHuang {
+                let formation = if chart.forward { Formation::FuYin } else { Formation::FanYin };
+                if !formations.contains(&formation) {
+                    formations.push(formation);
+                }
+            }
+        }
+
+        Ok(XuanKong {
+            period,
+            facing,
+            facing_mountain,
+            sitting_mountain,
+            facing_offset,
+            facing_palace,
+            sitting_palace,
+            replacement,
+            period_chart,
+            mountain_chart,
+            water_chart,
+            formations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mountains() {
+        assert_eq!(palace_of(Mountain::Zi), NinePalace::Kan);
+        assert_eq!(palace_of(Mountain::Gen), NinePalace::Gen);
+        // 四绿本宫为巽，与子同为天元者为巽山
+        assert_eq!(counterpart(NineStar::SiLu, Mountain::Zi), Mountain::Xun);
+        assert_eq!(replacement_star(Mountain::Mao), NineStar::ErHei);
+    }
+
+    #[test]
+    fn test_chart() {
+        // 八运子山午向：山星四顺飞、向星三逆飞，双星会向
+        let chart = XuanKong::new(NineStar::BaBai, 180.0).unwrap();
+        assert_eq!((chart.sitting_mountain, chart.facing_mountain), (Mountain::Zi, Mountain::Wu));
+        assert!(!chart.replacement);
+        assert_eq!(chart.mountain_chart.center, NineStar::SiLu);
+        assert!(chart.mountain_chart.forward);
+        assert_eq!(chart.water_chart.center, NineStar::SanBi);
+        assert!(!chart.water_chart.forward);
+        assert_eq!(chart.formations, [Formation::ShuangXingHuiXiang]);
+
+        // 八运未山丑向：五黄以坐山未（阴）逆飞，旺山旺向
+        let chart = XuanKong::new(NineStar::BaBai, 30.0).unwrap();
+        assert_eq!((chart.sitting_mountain, chart.facing_mountain), (Mountain::Wei, Mountain::Chou));
+        assert_eq!(chart.mountain_chart.center, NineStar::WuHuang);
+        assert_eq!(chart.formations, [Formation::WangShanWangXiang, Formation::FanYin]);
+
+        // 八运午山子向：双星会坐
+        let chart = XuanKong::new(NineStar::BaBai, 0.0).unwrap();
+        assert_eq!(chart.formations, [Formation::ShuangXingHuiZuo]);
+
+        assert!(XuanKong::new(NineStar::BaBai, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_replacement() {
+        // 八运子山午向兼丁：巽替为武曲六白顺飞，卯替为巨门二黑逆飞
+        let chart = XuanKong::new(NineStar::BaBai, 186.0).unwrap();
+        assert_eq!(chart.facing_mountain, Mountain::Wu);
+        assert!(chart.replacement);
+        assert_eq!(chart.mountain_chart.center, NineStar::LiuBai);
+        assert!(chart.mountain_chart.forward);
+        assert_eq!(chart.water_chart.center, NineStar::ErHei);
+        assert!(!chart.water_chart.forward);
+    }
+}
@@ -1,11 +1,45 @@
-use mingbu::metaphysics::ba_zi_json;
+use mingbu::metaphysics::{ba_zi_json, ba_zi_json_with};
+use mingbu::json::{ConceptStyle, JsonOptions};
 
 #[test]
 fn test_ba_zi_json_structure() {
     let json_str = ba_zi_json(1990, 5, 15, 10).unwrap();
     let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
     
-    assert!(parsed["year"].is_array());
+    assert!(parsed["year"].is_object());
+    assert!(parsed["year"]["stem"].is_string());
+    assert!(parsed["year"]["branch"].is_string());
     assert!(parsed["day_master"].is_string());
-    assert_eq!(parsed["year"].as_array().unwrap().len(), 2);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_ba_zi_json_styles() {
+    let chinese = ba_zi_json_with(1990, 5, 15, 10, &JsonOptions::with_style(ConceptStyle::Chinese)).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&chinese).unwrap();
+    assert_eq!(parsed["year"].as_str().unwrap().chars().count(), 2);
+
+    let rich = ba_zi_json_with(1990, 5, 15, 10, &JsonOptions::with_style(ConceptStyle::Rich)).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&rich).unwrap();
+    assert!(parsed["year"]["nayin"]["name"].is_string());
+    assert!(parsed["day_master"]["chinese"].is_string());
+}
+
+#[cfg(feature = "ephemeris")]
+#[test]
+fn test_qi_zheng_json() {
+    let beijing = mingbu::metaphysics::qi_zheng::Location { longitude: 116.4, latitude: 39.9 };
+    let zi_qi = mingbu::metaphysics::qi_zheng::ZiQiModel::new(2451545.0, 0.0);
+    let json_str = mingbu::qi_zheng_json(2451545.0, beijing, zi_qi).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    let longitude = parsed["sun"]["longitude"].as_f64().unwrap();
+    assert!((longitude - 280.37).abs() < 0.01);
+    assert!(parsed["saturn"]["retrograde"].as_bool().unwrap());
+    assert!(parsed["luo_hou"]["longitude"].is_f64());
+    assert_eq!(parsed["options"]["remainders"]["node"], "Mean");
+    assert_eq!(parsed["options"]["remainders"]["zi_qi"]["period_years"], 28.0);
+    assert_eq!(parsed["options"]["mansions"]["boundary"], "Modern");
+    assert_eq!(parsed["placements"][0]["body"], "Sun");
+    assert_eq!(parsed["placements"][0]["mansion"], "DouMuXie");
+    assert_eq!(parsed["palaces"][0]["palace"], "Ming");
+    assert!(parsed["ming_mansion"]["degree"].is_f64());
+}